@@ -0,0 +1,119 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as Tokens;
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Field, Fields, Index, Result};
+
+/// Derives `Interpolate` by blending every field.
+///
+/// Fields marked with `#[interpolate(skip)]` are not blended;
+/// they are cloned from the later value, thus they step.
+/// Only structs can be derived.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Interpolate, Sec};
+///
+/// #[derive(Interpolate, Clone, Copy, Debug, PartialEq)]
+/// struct Ball {
+///     pos: [f64; 2],
+///     age: Sec,
+///     #[interpolate(skip)]
+///     id: u32,
+/// }
+///
+/// let pre = Ball { pos: [0.0, 0.0], age: Sec::ZERO, id: 1 };
+/// let cur = Ball { pos: [2.0, 4.0], age: Sec::ONE, id: 2 };
+///
+/// assert_eq!(
+///     Ball { pos: [1.0, 2.0], age: Sec::new(0.5), id: 2 },
+///     pre.lerp(&cur, 0.5)
+/// );
+/// ```
+#[proc_macro_derive(Interpolate, attributes(interpolate))]
+pub fn derive_interpolate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive(mut input: DeriveInput) -> Result<Tokens> {
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "only structs can derive `Interpolate`",
+            ))
+        }
+    };
+
+    let body = match &data.fields {
+        Fields::Named(fields) => {
+            let fields = fields
+                .named
+                .iter()
+                .map(|field| {
+                    let name = field.ident.as_ref().unwrap();
+                    let value = blend(field, name)?;
+                    Ok(quote!(#name: #value))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote!(Self { #(#fields,)* })
+        }
+        Fields::Unnamed(fields) => {
+            let fields = fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, field)| blend(field, Index::from(i)))
+                .collect::<Result<Vec<_>>>()?;
+            quote!(Self(#(#fields,)*))
+        }
+        Fields::Unit => quote!(Self),
+    };
+
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::min_timer::Interpolate));
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::min_timer::Interpolate for #name #ty_generics #where_clause {
+            fn lerp(&self, other: &Self, alpha: f64) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+/// Creates the expression that blends the field.
+fn blend(field: &Field, member: impl ToTokens) -> Result<Tokens> {
+    if skipped(field)? {
+        Ok(quote!(::core::clone::Clone::clone(&other.#member)))
+    } else {
+        Ok(quote!(::min_timer::Interpolate::lerp(&self.#member, &other.#member, alpha)))
+    }
+}
+
+/// Checks whether the field is marked to be skipped.
+fn skipped(field: &Field) -> Result<bool> {
+    let mut skip = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("interpolate"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
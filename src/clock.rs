@@ -0,0 +1,194 @@
+use crate::{Moment, Nanos, Now};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// [Now] that uses the [wall clock](std::time::SystemTime), relative to the Unix epoch.
+///
+/// Use it for time stamps that are meaningful outside the process.
+/// Unlike the others, it jumps when the system time is changed;
+/// thus, it is not fit for measuring durations.
+/// Times before the epoch are negative.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Now, Sec, Wall};
+///
+/// let wall = Wall::new();
+///
+/// assert!(wall.now().sec() > Sec::from_days(365.0 * 50.0)); // after 2020
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Wall;
+
+impl Wall {
+    /// Creates.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Now for Wall {
+    fn now(&self) -> Moment {
+        Moment::new(self.nanos().as_sec())
+    }
+
+    fn nanos(&self) -> Nanos {
+        let now = SystemTime::now();
+        match now.duration_since(UNIX_EPOCH) {
+            Ok(dur) => Nanos::try_from(dur).unwrap_or(Nanos::MAX),
+            Err(err) => -Nanos::try_from(err.duration()).unwrap_or(Nanos::MAX),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use crate::{Moment, Nanos, Now};
+    use libc::{
+        clockid_t, timespec, CLOCK_MONOTONIC_RAW, CLOCK_PROCESS_CPUTIME_ID, CLOCK_THREAD_CPUTIME_ID,
+    };
+    use std::marker::PhantomData;
+
+    /// Reads the clock with the given identifier.
+    // Time is not 64 bits on every target.
+    #[allow(clippy::unnecessary_cast)]
+    fn get(clock: clockid_t) -> Nanos {
+        let mut tp = timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // Only writes to the given timespec.
+        let res = unsafe { libc::clock_gettime(clock, &mut tp) };
+        assert!(res == 0, "clock {} is not supported", clock);
+        Nanos::new(tp.tv_sec as i64 * 1_000_000_000 + tp.tv_nsec as i64)
+    }
+
+    /// [Now] that counts the CPU time of the whole process, on Linux.
+    ///
+    /// Only the time the threads of the process spend computing passes,
+    /// thus profiling with it separates computing from waiting.
+    /// Starts at the time the process has spent so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::{Cpu, Sec, Timer};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let cpu = Cpu::new();
+    /// let timer = Timer::new(&cpu);
+    ///
+    /// thread::sleep(Duration::from_millis(50));
+    ///
+    /// assert!(timer < Sec::from_millis(25.0)); // sleeping takes no CPU time
+    /// ```
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Cpu;
+
+    impl Cpu {
+        /// Creates.
+        pub const fn new() -> Self {
+            Self
+        }
+    }
+
+    impl Now for Cpu {
+        fn now(&self) -> Moment {
+            Moment::new(self.nanos().as_sec())
+        }
+
+        fn nanos(&self) -> Nanos {
+            get(CLOCK_PROCESS_CPUTIME_ID)
+        }
+    }
+
+    /// [Now] that counts the CPU time of the calling thread, on Linux.
+    ///
+    /// Like [Cpu], but the other threads do not count.
+    /// Each thread has its own time; thus, it cannot be sent or shared between threads,
+    /// and neither can the timers on it.
+    /// Starts at the time the calling thread has spent so far.
+    ///
+    /// ```compile_fail
+    /// use min_timer::{ThreadCpu, Timer};
+    /// use std::thread;
+    ///
+    /// let cpu = Box::leak(Box::new(ThreadCpu::new()));
+    /// let timer = Timer::new(cpu);
+    ///
+    /// thread::spawn(move || timer.elapsed());
+    /// ```
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct ThreadCpu {
+        _thread: PhantomData<*const ()>,
+    }
+
+    impl ThreadCpu {
+        /// Creates.
+        pub const fn new() -> Self {
+            Self {
+                _thread: PhantomData,
+            }
+        }
+    }
+
+    impl Now for ThreadCpu {
+        fn now(&self) -> Moment {
+            Moment::new(self.nanos().as_sec())
+        }
+
+        fn nanos(&self) -> Nanos {
+            get(CLOCK_THREAD_CPUTIME_ID)
+        }
+    }
+
+    /// [Now] that uses `CLOCK_MONOTONIC_RAW`, on Linux.
+    ///
+    /// Unlike [Std](crate::Std), the rate is not adjusted by NTP;
+    /// it is the raw rate of the hardware.
+    /// Starts at zero when created, so that `f64` keeps the precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::{Now, Raw, Sec};
+    ///
+    /// let raw = Raw::new();
+    /// let start = raw.now();
+    ///
+    /// assert!(raw.now() - start >= Sec::ZERO);
+    /// assert!(start.sec() < Sec::ONE);
+    /// ```
+    #[derive(Debug, Clone, Copy)]
+    pub struct Raw {
+        start: Nanos,
+    }
+
+    impl Default for Raw {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Raw {
+        /// Creates from the current time.
+        pub fn new() -> Self {
+            Self {
+                start: get(CLOCK_MONOTONIC_RAW),
+            }
+        }
+    }
+
+    impl Now for Raw {
+        fn now(&self) -> Moment {
+            Moment::new(self.nanos().as_sec())
+        }
+
+        fn nanos(&self) -> Nanos {
+            get(CLOCK_MONOTONIC_RAW) - self.start
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use linux::*;
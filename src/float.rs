@@ -0,0 +1,83 @@
+//! Rounding and powers of `f64`, which are only in the standard library.
+//! They are written here so that they work the same with or without it.
+
+/// Largest amount that can have a fraction; all the larger ones are whole.
+const WHOLE: f64 = 4503599627370496.0; // 2^52
+
+/// Rounds towards zero.
+pub(crate) fn trunc(x: f64) -> f64 {
+    if x.abs() < WHOLE {
+        // Casting drops the sign of the zero.
+        (x as i64 as f64).copysign(x)
+    } else {
+        x
+    }
+}
+
+/// Rounds towards negative infinity.
+pub(crate) fn floor(x: f64) -> f64 {
+    let whole = trunc(x);
+    if x < whole {
+        whole - 1.0
+    } else {
+        whole
+    }
+}
+
+/// Rounds to the nearest, and halfway cases away from zero.
+pub(crate) fn round(x: f64) -> f64 {
+    let whole = trunc(x);
+    if (x - whole).abs() >= 0.5 {
+        whole + 1f64.copysign(x)
+    } else {
+        whole
+    }
+}
+
+/// Returns the fraction, which has the sign of the amount.
+pub(crate) fn fract(x: f64) -> f64 {
+    x - trunc(x)
+}
+
+/// Raises to an integer power by squaring; powers of ten are exact up to 10^22.
+pub(crate) fn powi(x: f64, pow: i32) -> f64 {
+    let mut base = x;
+    let mut left = pow.unsigned_abs();
+    let mut res = 1.0;
+    while left != 0 {
+        if left & 1 == 1 {
+            res *= base;
+        }
+        base *= base;
+        left >>= 1;
+    }
+    if pow < 0 {
+        1.0 / res
+    } else {
+        res
+    }
+}
+
+/// Finds the quotient that leaves a remainder that is never negative.
+pub(crate) fn div_euclid(x: f64, y: f64) -> f64 {
+    let q = trunc(x / y);
+    if x % y < 0.0 {
+        if y > 0.0 {
+            q - 1.0
+        } else {
+            q + 1.0
+        }
+    } else {
+        q
+    }
+}
+
+/// Finds the remainder that is never negative.
+pub(crate) fn rem_euclid(x: f64, y: f64) -> f64 {
+    let r = x % y;
+    if r < 0.0 {
+        r + y.abs()
+    } else {
+        r
+    }
+}
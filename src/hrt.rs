@@ -1,50 +1,264 @@
-use crate::{now::Now, Prf, Sec, Stat, Timer};
-use std::ops::{Add, Mul};
+use crate::{now::Now, Hz, Interpolate, Prf, Sec, Stat, StopHandle, Timer};
+use std::{
+    error::Error,
+    fmt::Display,
+    hint, thread,
+    time::{Duration, Instant},
+};
 
 /// Rendering limitations.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Lim {
     /// 0 FPS.
     Never,
     /// 1 FPS.
     Once,
+    /// Given FPS; frames are spaced evenly.
+    /// Not positive rates are treated as 0 FPS.
+    Fps(Hz),
     /// Unlimited FPS.
+    #[default]
     Always,
 }
 
 impl Lim {
-    fn draw(&self, rate: u64) -> bool {
-        match self {
-            Self::Never => false,
-            Self::Once => rate == 0,
-            Self::Always => true,
+    /// Finds the time left to the next frame, if there is one.
+    /// Frame is the time since the previous frame was due,
+    /// and sec is the time since the start of the current second.
+    fn wait(&self, rate: u64, frame: Sec, sec: Sec) -> Option<Sec> {
+        match *self {
+            Self::Never => None,
+            Self::Once if rate == 0 => Some(Sec::ZERO),
+            Self::Once => Some(Sec::ONE - sec),
+            Self::Fps(fps) if fps > Hz::ZERO => Some(fps.period() - frame),
+            Self::Fps(_) => None,
+            Self::Always => Some(Sec::ZERO),
         }
     }
 }
 
-impl Default for Lim {
-    fn default() -> Self {
-        Self::Always
+/// Catching up policy of the heart when the updates fall behind.
+///
+/// After a long stall, like a debugger pause or a slow map load, many ticks pile up.
+/// Running all of them at once freezes the application until they are done.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Catch, Hrt, Hz, Std};
+///
+/// let now = Std::new();
+/// let mut hrt = Hrt::new(Hz::new(60.0), &now);
+///
+/// // After a stall, run at most four ticks and skip the rest.
+/// hrt.set_catch(Catch::Drop(4));
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Catch {
+    /// Runs as many updates as it takes to catch up.
+    #[default]
+    All,
+    /// Runs at most the given amount of updates per frame, but at least one,
+    /// and drops the rest of the whole ticks.
+    /// Game time jumps forward to real time.
+    Drop(u32),
+    /// Runs at most the given amount of updates per frame, but at least one,
+    /// and leaves the rest to the later frames.
+    /// Game time slows down until it catches up with real time.
+    Slow(u32),
+}
+
+impl Catch {
+    /// Returns the maximum amount of updates per frame, if there is one.
+    fn cap(&self) -> Option<u32> {
+        match *self {
+            Self::All => None,
+            // Zero would never update.
+            Self::Drop(max) | Self::Slow(max) => Some(max.max(1)),
+        }
+    }
+}
+
+/// Waiting strategy of the heart between ticks and frames.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Hrt, Hz, Idle, Lim, Sec, Std};
+///
+/// let now = Std::new();
+/// let mut hrt = Hrt::new(Hz::new(60.0), &now);
+///
+/// // Doing nothing most of the time.
+/// hrt.set_lim(Lim::Fps(Hz::new(30.0)));
+/// hrt.set_idle(Idle::Hybrid(Sec::MILLI));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Idle {
+    /// Busy-waits until the next tick or frame.
+    /// Most precise, but keeps a core busy.
+    #[default]
+    Spin,
+    /// Sleeps until the next tick or frame.
+    /// Frees the core, but the operating system might oversleep.
+    /// Wakes up once early to see how fast the clock runs, in case it is not the real time.
+    Sleep,
+    /// Sleeps until the given amount is left to the next tick or frame,
+    /// then busy-waits for the rest.
+    /// The amount is kept between zero and the time to wait;
+    /// not a number is treated as zero.
+    Hybrid(Sec),
+}
+
+impl Idle {
+    /// Waits for the given duration of the clock,
+    /// but not longer than the same duration of real time.
+    /// Thus, clocks that are slowed down or paused do not block the heart.
+    fn wait<T: Now>(&self, now: &T, dur: Sec) {
+        let timer = Timer::new(now);
+        let real = Instant::now();
+        let limit = dur.saturating_duration();
+        match *self {
+            Self::Spin => Self::spin(timer, real, dur, limit),
+            Self::Sleep => Self::sleep(timer, real, dur, limit),
+            Self::Hybrid(spin) => {
+                let spin = spin.max(Sec::ZERO).min(dur);
+                Self::sleep(timer, real, dur - spin, limit);
+                Self::spin(timer, real, dur, limit);
+            }
+        }
+    }
+
+    fn spin<T: Now>(timer: Timer<T>, real: Instant, dur: Sec, limit: Duration) {
+        while timer.elapsed() < dur && real.elapsed() < limit {
+            hint::spin_loop();
+        }
+    }
+
+    /// Sleeps for the real time the rest takes at the rate the clock has run so far.
+    /// Wakes up early once to measure the rate; thus, clocks faster than the real time are not overslept.
+    fn sleep<T: Now>(timer: Timer<T>, real: Instant, dur: Sec, limit: Duration) {
+        let mut nap = SLICE;
+        loop {
+            let left = dur - timer.elapsed();
+            let real_left = limit.saturating_sub(real.elapsed());
+            if left <= Sec::ZERO || real_left.is_zero() {
+                return;
+            }
+            thread::sleep(nap.min(left.saturating_duration()).min(real_left));
+            let rate = timer.elapsed() / Sec::from(real.elapsed());
+            // Paused clocks are waited for until the limit.
+            nap = if rate > 0.0 {
+                ((dur - timer.elapsed()) / rate).saturating_duration()
+            } else {
+                real_left
+            };
+        }
     }
 }
 
+/// Real time that the first sleep of a wait takes at most, for measuring the rate of the clock.
+const SLICE: Duration = Duration::from_millis(1);
+
 /// State of an application the heart runs.
-pub trait Stt<T: Now>: Default + Copy + Add<Self, Output = Self> + Mul<f64, Output = Self> {
+///
+/// The heart stops at the first error.
+pub trait Stt<T: Now>: Copy + Interpolate {
+    /// Error of the application.
+    /// Use [Infallible](std::convert::Infallible) if nothing can fail.
+    type Err;
+
     /// Initializes the state at the start.
     /// Passed timer can be used to measure initialization time.
-    fn init(&mut self, hrt: &mut Hrt<T>, timer: Timer<T>);
+    fn init(&mut self, hrt: &mut Hrt<T>, timer: Timer<T>) -> Result<(), Self::Err>;
 
     /// Updates the state each tick.
-    fn update(&mut self, hrt: &mut Hrt<T>);
+    fn update(&mut self, hrt: &mut Hrt<T>) -> Result<(), Self::Err>;
 
     /// Profiles the state each second.
-    fn sec(&mut self, hrt: &mut Hrt<T>);
+    fn sec(&mut self, hrt: &mut Hrt<T>) -> Result<(), Self::Err>;
 }
 
 /// Renderer of an application the heart runs.
-pub trait Render<T: Now, U: Stt<T>>: Default {
+///
+/// Errors are of the application, which is defined by the state.
+pub trait Render<T: Now, U: Stt<T>> {
     /// Renders the state each frame.
-    fn render(&mut self, hrt: &Hrt<T>, stt: &U);
+    fn render(&mut self, hrt: &Hrt<T>, stt: &U) -> Result<(), U::Err>;
+}
+
+/// Error that stopped the heart, with the statistics at the point of failure.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Hrt, Hz, Render, Std, Stt};
+/// use std::fmt::Display;
+///
+/// fn main_loop<U: Stt<Std>, V: Render<Std, U>>(stt: U, ren: V)
+/// where
+///     U::Err: Display,
+/// {
+///     let now = Std::new();
+///     let mut hrt = Hrt::new(Hz::new(60.0), &now);
+///     if let Err(fail) = hrt.run(stt, ren) {
+///         eprintln!("{}", fail); // heart failed after .. ticks and .. frames: ..
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fail<E> {
+    /// Error returned from the state or the renderer.
+    pub err: E,
+    /// Update statistics when the error happened.
+    pub ticks: Stat,
+    /// Draw statistics when the error happened.
+    pub frames: Stat,
+}
+
+impl<E: Display> Display for Fail<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "heart failed after {} ticks and {} frames: {}",
+            self.ticks.count(),
+            self.frames.count(),
+            self.err
+        )
+    }
+}
+
+impl<E: Error> Error for Fail<E> {}
+
+/// Limits of a headless run, which does not wait for the clock.
+///
+/// Pausing has no effect on a headless run.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct Sim {
+    /// Amount of ticks to run; if there is none, runs until stopped.
+    pub ticks: Option<u64>,
+    /// Renders after every given amount of ticks; if there is none or it is zero, never renders.
+    pub every: Option<u64>,
+}
+
+/// Previous and current states of an application the heart runs.
+/// Rendering interpolates between them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pair<U> {
+    /// State before the last update.
+    pub pre: U,
+    /// State after the last update.
+    pub cur: U,
+}
+
+impl<U: Copy> Pair<U> {
+    /// Creates with the same previous and current states.
+    pub fn new(stt: U) -> Self {
+        Self { pre: stt, cur: stt }
+    }
 }
 
 /// Heart of a real-time application.
@@ -53,13 +267,13 @@ pub trait Render<T: Now, U: Stt<T>>: Default {
 /// Updates and renders are decoupled:
 /// the tick rate can be much lower than the frame rate.
 /// Smooth graphics are achived by interpolating the current and previous ticks when rendering.
-/// The distance from the previous tick is captured by the member [rem], which is the short for remaining ticks.
+/// The distance from the previous tick is the fraction of a tick that remains, which is passed to [Interpolate::lerp].
 ///
 /// # Example
 ///
 /// ```
-/// use min_timer::{Hrt, Now, Render, Std, Stt, Timer};
-/// use std::ops::{Add, Mul};
+/// use min_timer::{Hrt, Hz, Interpolate, Now, Render, Std, Stt, Timer};
+/// use std::convert::Infallible;
 ///
 /// struct Bar {
 ///     len: u32,
@@ -92,7 +306,7 @@ pub trait Render<T: Now, U: Stt<T>>: Default {
 ///
 /// impl<T: Now> Render<T, Ex> for Bar {
 ///     // Rendering
-///     fn render(&mut self, _: &Hrt<T>, stt: &Ex) {
+///     fn render(&mut self, _: &Hrt<T>, stt: &Ex) -> Result<(), Infallible> {
 ///         let len = self.len as f64 * stt.0;
 ///         let len = len.floor() as u32;
 ///         let len = len.min(self.len);
@@ -104,80 +318,160 @@ pub trait Render<T: Now, U: Stt<T>>: Default {
 ///         } else {
 ///             self.print(per, len);
 ///         }
+///         Ok(())
 ///     }
 /// }
 ///
 /// #[derive(Default, Clone, Copy)]
 /// struct Ex(f64);
 ///
-/// impl Mul<f64> for Ex {
-///     type Output = Ex;
-///
-///     // Scaling
-///     fn mul(self, rhs: f64) -> Self::Output {
-///         Self(self.0 * rhs)
-///     }
-/// }
-///
-/// impl Add for Ex {
-///     type Output = Ex;
-///
-///     // Superposing
-///     fn add(self, rhs: Ex) -> Self::Output {
-///         Self(self.0 + rhs.0)
+/// impl Interpolate for Ex {
+///     // Blending
+///     fn lerp(&self, other: &Self, alpha: f64) -> Self {
+///         Self(self.0.lerp(&other.0, alpha))
 ///     }
 /// }
 ///
 /// impl<T: Now> Stt<T> for Ex {
+///     type Err = Infallible;
+///
 ///     // Initialization; timer provided for profiling
-///     fn init(&mut self, _: &mut Hrt<T>, timer: Timer<T>) {
+///     fn init(&mut self, _: &mut Hrt<T>, timer: Timer<T>) -> Result<(), Self::Err> {
 ///         println!("Initialization done in {}!", timer);
+///         Ok(())
 ///     }
 ///
 ///     // Updating; heart provided for manuplation
-///     fn update(&mut self, hrt: &mut Hrt<T>) {
+///     fn update(&mut self, hrt: &mut Hrt<T>) -> Result<(), Self::Err> {
 ///         self.0 += 1e-1;
 ///         if self.0 >= 1.0 {
 ///             hrt.stop();
 ///         }
+///         Ok(())
 ///     }
 ///
 ///     // Profiling every second; heart provided for manuplation
-///     fn sec(&mut self, hrt: &mut Hrt<T>) {
+///     fn sec(&mut self, hrt: &mut Hrt<T>) -> Result<(), Self::Err> {
 ///         println!(
 ///             "Tick Rate: {} Frame Rate: {}",
 ///             hrt.ticks().avg_rate(),
 ///             hrt.frames().avg_rate()
 ///         );
+///         Ok(())
 ///     }
 /// }
 ///
 /// let now = Std::new(); // using the standard library's clock
-/// let mut hrt = Hrt::new(1e2, &now); // target tick rate 100.0
-/// hrt.start::<Ex, Bar>(); // creates from defaults
+/// let mut hrt = Hrt::new(Hz::new(1e2), &now); // target tick rate 100.0
+/// hrt.start::<Ex, Bar>().unwrap(); // creates from defaults
+/// ```
+///
+/// # Testing
+///
+/// Driving the heart with a [Manual](crate::Manual) clock makes it deterministic.
+///
+/// ```
+/// use min_timer::{Hrt, Hz, Interpolate, Manual, Now, Render, Sec, Stt, Timer};
+/// use std::convert::Infallible;
+///
+/// struct Clk;
+///
+/// impl Render<Manual, Cnt> for Clk {
+///     // Every frame takes a quarter of a tick.
+///     fn render(&mut self, hrt: &Hrt<Manual>, _: &Cnt) -> Result<(), Infallible> {
+///         hrt.now().advance(Sec::new(1.0 / 256.0));
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// struct Cnt(u32);
+///
+/// # impl Interpolate for Cnt {
+/// #     fn lerp(&self, other: &Self, _: f64) -> Self {
+/// #         *other
+/// #     }
+/// # }
+/// #
+/// impl Stt<Manual> for Cnt {
+///     type Err = Infallible;
+///
+///     fn init(&mut self, _: &mut Hrt<Manual>, timer: Timer<Manual>) -> Result<(), Self::Err> {
+///         assert!(timer == Sec::ZERO);
+///         Ok(())
+///     }
+///
+///     fn update(&mut self, hrt: &mut Hrt<Manual>) -> Result<(), Self::Err> {
+///         self.0 += 1;
+///         if self.0 == 64 {
+///             hrt.stop();
+///         }
+///         Ok(())
+///     }
+///
+///     fn sec(&mut self, hrt: &mut Hrt<Manual>) -> Result<(), Self::Err> {
+///         assert_eq!(63, hrt.ticks().rate());
+///         assert_eq!(256, hrt.frames().rate());
+///         Ok(())
+///     }
+/// }
+///
+/// let now = Manual::new();
+/// let mut hrt = Hrt::new(Hz::new(64.0), &now);
+/// let (cnt, _) = hrt.run(Cnt(0), Clk).unwrap();
+///
+/// assert_eq!(64, cnt.0);
+/// assert_eq!(64, hrt.ticks().count());
+/// assert_eq!(257, hrt.frames().count());
+/// assert_eq!(Sec::new(257.0 / 256.0), now.now().sec());
 /// ```
 pub struct Hrt<'a, T: Now> {
     beat: bool,
     lim: Lim,
+    idle: Idle,
+    catch: Catch,
     tar: Sec,
     now: &'a T,
+    sec: Timer<'a, T>,
+    iter: Timer<'a, T>,
+    frame: Timer<'a, T>,
     ticks: Stat,
     frames: Stat,
+    drops: Stat,
+    rest: Sec,
+    rested: Sec,
+    handle: StopHandle,
+    hold: Option<Sec>,
 }
 
 impl<'a, T: Now> Hrt<'a, T> {
     /// Creates with the given target tick rate, and closures for updating, drawing, and profiling at every second.
-    pub fn new(tar: f64, now: &'a T) -> Self {
+    pub fn new(tar: Hz, now: &'a T) -> Self {
         Self {
             beat: false,
             lim: Lim::default(),
-            tar: Sec::new(1.0 / tar),
+            idle: Idle::default(),
+            catch: Catch::default(),
+            tar: tar.period(),
             now,
+            sec: Timer::new(now),
+            iter: Timer::new(now),
+            frame: Timer::new(now),
             ticks: Stat::new(),
             frames: Stat::new(),
+            drops: Stat::new(),
+            rest: Sec::ZERO,
+            rested: Sec::ZERO,
+            handle: StopHandle::new(),
+            hold: None,
         }
     }
 
+    /// Returns the clock.
+    pub fn now(&self) -> &'a T {
+        self.now
+    }
+
     /// Returns update statistics.
     pub fn ticks(&self) -> &Stat {
         &self.ticks
@@ -188,59 +482,380 @@ impl<'a, T: Now> Hrt<'a, T> {
         &self.frames
     }
 
-    /// Starts the heart.
+    /// Returns dropped tick statistics.
+    /// Every dropped tick is recorded as a call that took a tick.
+    pub fn drops(&self) -> &Stat {
+        &self.drops
+    }
+
+    /// Returns the time spent idle in the previous second.
+    /// In the profiling callback, it is the time spent idle in the current second.
+    pub fn idled(&self) -> Sec {
+        self.rested
+    }
+
+    /// Returns a handle that can stop or pause the heart from other threads.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::{Hrt, Hz, Std};
+    /// use std::{thread, time::Duration};
+    ///
+    /// let now = Std::new();
+    /// let hrt = Hrt::new(Hz::new(60.0), &now);
+    /// let handle = hrt.handle();
+    ///
+    /// thread::spawn(move || {
+    ///     handle.pause();
+    ///     thread::sleep(Duration::from_secs(1));
+    ///     handle.stop(); // the heart profiles one last time and returns
+    /// });
+    /// ```
+    pub fn handle(&self) -> StopHandle {
+        self.handle.clone()
+    }
+
+    /// Returns whether the heart is running.
+    pub fn beating(&self) -> bool {
+        self.beat
+    }
+
+    /// Starts the heart with a default state and renderer.
+    /// Returns the first error, which stops the heart.
+    ///
+    /// # Panic
+    ///
+    /// If it is already running.
+    pub fn start<U: Stt<T> + Default, V: Render<T, U> + Default>(
+        &mut self,
+    ) -> Result<(), Fail<U::Err>> {
+        self.run(U::default(), V::default())?;
+        Ok(())
+    }
+
+    /// Starts the heart with the given state and renderer.
+    /// Hands them back after the heart stops,
+    /// or returns the first error, which stops the heart.
+    ///
+    /// # Panic
+    ///
+    /// If it is already running.
+    pub fn run<U: Stt<T>, V: Render<T, U>>(
+        &mut self,
+        stt: U,
+        mut ren: V,
+    ) -> Result<(U, V), Fail<U::Err>> {
+        let mut stt = self.begin(stt).map_err(|err| self.fail(err))?;
+
+        while self
+            .step(&mut stt, &mut ren)
+            .map_err(|err| self.fail(err))?
+        {
+            self.wait();
+        }
+
+        Ok((stt.cur, ren))
+    }
+
+    /// Starts the heart headless with the given state and renderer.
+    /// Updates as fast as possible instead of waiting for the clock.
+    /// Hands them back after the heart stops,
+    /// or returns the first error, which stops the heart.
+    ///
+    /// Time is virtual; every tick moves it forward by the target tick duration.
+    /// Thus, profiling happens every simulated second, at the tick nearest to it,
+    /// and the updates are the same as a real-time run that keeps up.
+    /// Rendering draws the current state, as it is right after the tick.
+    /// Tick and frame durations are still measured with the clock.
+    ///
+    /// The clock itself is not moved: [now](Hrt::now) still reads it,
+    /// thus the timers the state keeps run on its time instead of the virtual time.
+    /// States that keep timers can run on a [Manual](crate::Manual) clock,
+    /// and advance it by a tick in every update.
+    ///
+    /// # Panic
+    ///
+    /// If it is already running.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::{Fail, Hrt, Hz, Render, Sim, Std, Stt};
+    ///
+    /// // Fast-forwards an hour of the application, rendering once a second.
+    /// fn skip_hour<U: Stt<Std>, V: Render<Std, U>>(stt: U, ren: V) -> Result<U, Fail<U::Err>> {
+    ///     let now = Std::new();
+    ///     let mut hrt = Hrt::new(Hz::new(60.0), &now);
+    ///     let sim = Sim {
+    ///         ticks: Some(60 * 60 * 60),
+    ///         every: Some(60),
+    ///     };
+    ///     hrt.sim(stt, ren, sim).map(|(stt, _)| stt)
+    /// }
+    /// ```
+    pub fn sim<U: Stt<T>, V: Render<T, U>>(
+        &mut self,
+        stt: U,
+        mut ren: V,
+        sim: Sim,
+    ) -> Result<(U, V), Fail<U::Err>> {
+        let mut stt = self.begin(stt).map_err(|err| self.fail(err))?;
+
+        if let Err(err) = self.fast(&mut stt, &mut ren, sim) {
+            self.halt();
+            return Err(self.fail(err));
+        }
+
+        Ok((stt.cur, ren))
+    }
+
+    fn fast<U: Stt<T>, V: Render<T, U>>(
+        &mut self,
+        stt: &mut Pair<U>,
+        ren: &mut V,
+        sim: Sim,
+    ) -> Result<(), U::Err> {
+        let every = sim.every.filter(|&every| every > 0);
+        let mut ticks = 0;
+        let mut secs = 0;
+
+        while self.beat && sim.ticks.is_none_or(|max| ticks < max) {
+            if self.handle.stopped() {
+                self.handle.reset();
+                self.halt();
+                return self.profile(stt);
+            }
+
+            let mut stat = self.ticks;
+            let res = Prf::scope(self.now, &mut stat, || {
+                stt.pre = stt.cur;
+                stt.cur.update(self)
+            });
+            self.ticks = stat;
+            res?;
+            ticks += 1;
+
+            if every.is_some_and(|every| ticks % every == 0) {
+                let mut stat = self.frames;
+                let res = Prf::scope(self.now, &mut stat, || ren.render(self, &stt.cur));
+                self.frames = stat;
+                res?;
+            }
+
+            // Computed from the count, since summing the ticks drifts.
+            // The second is due at the tick nearest to it.
+            let time = self.tar * ticks as f64;
+            if time >= Sec::new((secs + 1) as f64) - self.tar * 0.5 {
+                secs += 1;
+                self.profile(stt)?;
+            }
+        }
+
+        self.halt();
+        Ok(())
+    }
+
+    /// Stops the heart, and gives the signals back to the process.
+    fn halt(&mut self) {
+        self.beat = false;
+        self.handle.release();
+    }
+
+    fn fail<E>(&self, err: E) -> Fail<E> {
+        Fail {
+            err,
+            ticks: self.ticks,
+            frames: self.frames,
+        }
+    }
+
+    /// Initializes the state and starts the heart without running it.
+    /// The returned states must be stepped by the caller.
+    ///
+    /// Use this with [step](Hrt::step) and [wait](Hrt::wait) for running the heart inside an external event loop.
     ///
     /// # Panic
     ///
     /// If it is already running.
-    pub fn start<U: Stt<T>, V: Render<T, U>>(&mut self) {
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::{Hrt, Hz, Render, Std, Stt};
+    /// use std::thread;
+    ///
+    /// fn drive<U: Stt<Std>, V: Render<Std, U>>(stt: U, mut ren: V) -> Result<(), U::Err> {
+    ///     let now = Std::new();
+    ///     let mut hrt = Hrt::new(Hz::new(60.0), &now);
+    ///     let mut stt = hrt.begin(stt)?;
+    ///
+    ///     // The event loop, which owns the thread.
+    ///     while hrt.step(&mut stt, &mut ren)? {
+    ///         // Handle the events, then wait until the next tick, frame or second.
+    ///         thread::sleep(hrt.next().saturating_duration());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn begin<U: Stt<T>>(&mut self, mut stt: U) -> Result<Pair<U>, U::Err> {
         if self.beat {
             panic!("Already running!")
         }
         self.beat = true;
 
         let init = Timer::new(self.now);
-        let ren = V::default();
-        let mut cur = U::default();
-        cur.init(self, init);
+        if let Err(err) = stt.init(self, init) {
+            self.halt();
+            return Err(err);
+        }
+
+        self.sec.refresh();
+        self.iter.refresh();
+        self.frame.refresh();
+        self.rest = Sec::ZERO;
 
-        self.beat(cur, ren);
+        Ok(Pair::new(stt))
     }
 
-    fn beat<U: Stt<T>, V: Render<T, U>>(&mut self, mut cur: U, mut ren: V) {
-        let mut pre = U::default();
-        let mut sec = Timer::new(self.now);
-        let mut iter = Timer::new(self.now);
+    /// Runs the due updates, renders once if the limit allows, and profiles if a second passed.
+    /// Returns whether the heart is still running,
+    /// or the first error, which stops the heart.
+    ///
+    /// Does not wait; the caller decides when to step again.
+    /// Does nothing if the heart is not running, like before [begin](Hrt::begin) or after it stopped.
+    pub fn step<U: Stt<T>, V: Render<T, U>>(
+        &mut self,
+        stt: &mut Pair<U>,
+        ren: &mut V,
+    ) -> Result<bool, U::Err> {
+        if !self.beat {
+            return Ok(false);
+        }
+        let res = self.pump(stt, ren);
+        if res.is_err() {
+            self.halt();
+        }
+        res
+    }
 
-        while self.beat {
-            while iter >= self.tar {
-                let _ = Prf::new(self.now, &mut self.ticks);
-                pre = cur;
-                cur.update(self);
-                iter -= self.tar;
-            }
+    fn pump<U: Stt<T>, V: Render<T, U>>(
+        &mut self,
+        stt: &mut Pair<U>,
+        ren: &mut V,
+    ) -> Result<bool, U::Err> {
+        if self.handle.stopped() {
+            self.handle.reset();
+            self.halt();
+            self.profile(stt)?;
+            return Ok(false);
+        }
+
+        if self.handle.paused() {
+            let hold = *self.hold.get_or_insert(self.iter.elapsed());
+            self.freeze(hold);
+        } else if let Some(hold) = self.hold.take() {
+            self.freeze(hold);
+        }
 
-            if self.lim.draw(self.frames.rate()) {
-                let _ = Prf::new(self.now, &mut self.frames);
-                let rem = (iter / self.tar.as_f64()).as_f64();
-                let drawn = pre * (1.0 - rem) + cur * rem;
-                ren.render(self, &drawn);
+        let mut updates = 0;
+        while self.hold.is_none() && self.iter >= self.tar {
+            if Some(updates) == self.catch.cap() {
+                if let Catch::Drop(_) = self.catch {
+                    let dropped = self.iter.elapsed().div_euclid(self.tar);
+                    self.iter -= self.tar * dropped;
+                    self.drops.record(self.tar * dropped, dropped as u64);
+                }
+                break;
             }
+            updates += 1;
+            let mut ticks = self.ticks;
+            let res = Prf::scope(self.now, &mut ticks, || {
+                stt.pre = stt.cur;
+                stt.cur.update(self)
+            });
+            self.ticks = ticks;
+            res?;
+            self.iter -= self.tar;
+        }
+
+        let next = self
+            .lim
+            .wait(self.frames.rate(), self.frame.elapsed(), self.sec.elapsed());
+        if matches!(next, Some(wait) if wait <= Sec::ZERO) {
+            let mut frames = self.frames;
+            let res = Prf::scope(self.now, &mut frames, || {
+                let rem = self.iter / self.tar;
+                let drawn = stt.pre.lerp(&stt.cur, rem);
+                ren.render(self, &drawn)
+            });
+            self.frames = frames;
+            res?;
 
-            if sec >= Sec::ONE {
-                sec -= Sec::ONE;
-                cur.sec(self);
-                self.ticks.refresh();
-                self.frames.refresh();
+            if let Lim::Fps(fps) = self.lim {
+                let per = fps.period();
+                self.frame -= per;
+                // Do not rush the missed frames.
+                if self.frame >= per {
+                    self.frame.refresh();
+                }
             }
         }
+
+        if self.sec >= Sec::ONE {
+            self.sec -= Sec::ONE;
+            self.profile(stt)?;
+        }
+
+        Ok(self.beat)
+    }
+
+    fn profile<U: Stt<T>>(&mut self, stt: &mut Pair<U>) -> Result<(), U::Err> {
+        self.rested = self.rest;
+        self.rest = Sec::ZERO;
+        let res = stt.cur.sec(self);
+        self.ticks.refresh();
+        self.frames.refresh();
+        self.drops.refresh();
+        res
+    }
+
+    /// Keeps the time since the last tick at the given amount.
+    fn freeze(&mut self, hold: Sec) {
+        self.iter.refresh();
+        self.iter -= -hold;
+    }
+
+    /// Finds the time left until the next tick, frame or second, whichever is the earliest.
+    /// External event loops can sleep this long before stepping again.
+    pub fn next(&self) -> Sec {
+        let mut next = (self.tar - self.iter).min(Sec::ONE - self.sec);
+        if let Some(frame) =
+            self.lim
+                .wait(self.frames.rate(), self.frame.elapsed(), self.sec.elapsed())
+        {
+            next = next.min(frame);
+        }
+        next
+    }
+
+    /// Waits until the next tick, frame or second with the waiting strategy.
+    /// The time spent is recorded as idle.
+    pub fn wait(&mut self) {
+        let wait = self.next();
+        if wait > Sec::ZERO {
+            Prf::scope(self.now, &mut self.rest, || self.idle.wait(self.now, wait));
+        }
     }
 
     /// Flags the heart to stop it.
     ///
     /// The heart might update many times and render once and profile once before stopping after this call in update.
+    /// A stopped heart can be started again.
+    ///
+    /// Use a [handle](Hrt::handle) for stopping from outside the heart.
     pub fn stop(&mut self) {
-        self.beat = false;
+        self.halt();
     }
 
     /// Sets the rendering limit.
@@ -255,4 +870,19 @@ impl<'a, T: Now> Hrt<'a, T> {
     pub fn set_lim(&mut self, lim: Lim) {
         self.lim = lim;
     }
+
+    /// Sets the catching up policy for when the updates fall behind.
+    ///
+    /// Running all the piled up updates is the default.
+    pub fn set_catch(&mut self, catch: Catch) {
+        self.catch = catch;
+    }
+
+    /// Sets the waiting strategy between ticks and frames.
+    ///
+    /// Spinning is the default, which keeps a core busy even when the rendering is limited.
+    /// Consider sleeping, or sleeping with a short spin at the end for precision, when the heart does not need the whole core.
+    pub fn set_idle(&mut self, idle: Idle) {
+        self.idle = idle;
+    }
 }
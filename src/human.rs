@@ -0,0 +1,76 @@
+//! [Sec] as a string with a unit, like `"250 ms"` or `"1h30m"`, for serde.
+//!
+//! Seconds are plain amounts by default.
+//! Use this module with `#[serde(with = "min_timer::human")]` to write them in the most fitting unit.
+//! Reading accepts anything that [parses](Sec#6-parsing--formatting), and plain amounts in seconds.
+//! Needs a self-describing format, like JSON or TOML.
+//!
+//! # Example
+//!
+//! ```
+//! use min_timer::Sec;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Settings {
+//!     #[serde(with = "min_timer::human")]
+//!     timeout: Sec,
+//!     delay: Sec,
+//! }
+//!
+//! let settings = Settings {
+//!     timeout: Sec::new(0.25),
+//!     delay: Sec::new(1.5),
+//! };
+//! let json = serde_json::to_string(&settings).unwrap();
+//!
+//! assert_eq!(r#"{"timeout":"250 ms","delay":1.5}"#, json);
+//! assert_eq!(settings, serde_json::from_str(&json).unwrap());
+//!
+//! let json = r#"{"timeout":"1h30m","delay":2}"#;
+//! let settings: Settings = serde_json::from_str(json).unwrap();
+//! assert_eq!(Sec::new(5400.0), settings.timeout);
+//! ```
+
+use crate::Sec;
+use core::fmt::{self, Formatter};
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+/// Writes in the most fitting unit.
+pub fn serialize<S: Serializer>(sec: &Sec, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(sec)
+}
+
+/// Reads a string with units, or a plain amount in seconds.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Sec, D::Error> {
+    deserializer.deserialize_any(Human)
+}
+
+struct Human;
+
+impl<'de> Visitor<'de> for Human {
+    type Value = Sec;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a duration like \"250 ms\" or an amount of seconds")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Sec::new(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Sec::new(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Sec::new(v as f64))
+    }
+}
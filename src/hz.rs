@@ -0,0 +1,186 @@
+use crate::{float, parse, unit, ParseSecError, ParseSecErrorKind, Sec};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Div, Mul},
+    str::FromStr,
+};
+
+/// Frequency in hertz, which is the amount of cycles in a second.
+///
+/// Formats in the most fitting prefix from milli hertz to giga hertz,
+/// and parses amounts with or without a symbol, like `60Hz`, `1 kHz` or `0.5`.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Hz, Sec};
+///
+/// let rate = Hz::new(50.0);
+///
+/// assert_eq!(Sec::new(0.02), rate.period());
+/// assert_eq!(rate, Sec::new(0.02).freq());
+/// assert_eq!(150.0, Sec::new(3.0) * rate); // cycles
+///
+/// assert_eq!("50 Hz", rate.to_string());
+/// assert_eq!("1.5 kHz", Hz::new(1500.0).to_string());
+/// assert_eq!(Hz::new(60.0), "60Hz".parse().unwrap());
+/// assert_eq!(Hz::new(1000.0), "1kHz".parse().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Hz(f64);
+
+/// Prefixes with their powers of ten, from the largest to the smallest.
+const PREFIXES: [(&str, i32); 5] = [("G", 9), ("M", 6), ("k", 3), ("", 0), ("m", -3)];
+
+/// Symbols with their powers of ten, with the plain hertz at the end since all of them end with it.
+const SYMBOLS: [(&str, i32); 5] = [("GHz", 9), ("MHz", 6), ("kHz", 3), ("mHz", -3), ("Hz", 0)];
+
+impl Hz {
+    /// Zero hertz.
+    pub const ZERO: Hz = Hz(0.0);
+
+    /// Creates from the given amount.
+    pub const fn new(amt: f64) -> Self {
+        Self(amt)
+    }
+
+    /// Returns the amount.
+    pub const fn as_f64(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the duration of a cycle.
+    pub const fn period(&self) -> Sec {
+        Sec::new(1.0 / self.0)
+    }
+}
+
+impl Sec {
+    /// Returns the frequency of cycles with this duration.
+    pub const fn freq(&self) -> Hz {
+        Hz(1.0 / self.as_f64())
+    }
+}
+
+impl From<f64> for Hz {
+    fn from(amt: f64) -> Self {
+        Self(amt)
+    }
+}
+
+impl From<Hz> for f64 {
+    fn from(hz: Hz) -> Self {
+        hz.0
+    }
+}
+
+impl Mul<Hz> for Sec {
+    type Output = f64;
+
+    fn mul(self, rhs: Hz) -> Self::Output {
+        self.as_f64() * rhs.0
+    }
+}
+
+impl Mul<Sec> for Hz {
+    type Output = f64;
+
+    fn mul(self, rhs: Sec) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<f64> for Hz {
+    type Output = Hz;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for Hz {
+    type Output = Hz;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Display for Hz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let amt = self.0.abs();
+        let (prefix, pow) = if amt == 0.0 || !amt.is_finite() {
+            ("", 0)
+        } else {
+            PREFIXES
+                .into_iter()
+                .find(|&(_, pow)| amt >= float::powi(10.0, pow))
+                .unwrap_or(("m", -3))
+        };
+        let scaled = Scaled {
+            amt: self.0,
+            pow,
+            prec: f.precision(),
+        };
+        unit::pad(f, format_args!("{} {}Hz", scaled, prefix))
+    }
+}
+
+/// Amount of hertz in the prefix with the given power.
+struct Scaled {
+    amt: f64,
+    pow: i32,
+    prec: Option<usize>,
+}
+
+impl Display for Scaled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.prec {
+            Some(prec) if self.pow < 0 => {
+                write!(f, "{:.*}", prec, self.amt * float::powi(10.0, -self.pow))
+            }
+            Some(prec) => write!(f, "{:.*}", prec, self.amt / float::powi(10.0, self.pow)),
+            None => unit::shift(f, self.amt, -self.pow),
+        }
+    }
+}
+
+impl FromStr for Hz {
+    type Err = ParseSecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fail = |kind, span| Err(ParseSecError::new(kind, span));
+
+        let end = s.trim_end().len();
+        let start = end - s.trim().len();
+        if start == end {
+            return fail(ParseSecErrorKind::Empty, 0..s.len());
+        }
+        let body = &s[..end];
+
+        let (num, pow) = match SYMBOLS.into_iter().find(|&(sym, _)| body.ends_with(sym)) {
+            Some((sym, pow)) => (start..end - sym.len(), pow),
+            None => {
+                if let Ok(amt) = body[start..].parse::<f64>() {
+                    return Ok(Self(amt));
+                }
+                // Symbol is the trailing run of letters.
+                let sym = body.trim_end_matches(|c: char| c.is_alphabetic()).len();
+                if sym == end {
+                    return fail(ParseSecErrorKind::Number, start..end);
+                }
+                return fail(ParseSecErrorKind::Unit, sym..end);
+            }
+        };
+        let num = num.start..num.start + s[num.clone()].trim_end().len();
+        match s[num.clone()].parse::<f64>() {
+            Ok(amt) => Ok(Self(parse::shift(&s[num], amt, pow))),
+            Err(_) => fail(ParseSecErrorKind::Number, num),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{float, parts, unit::Buf, ParseSecError, ParseSecErrorKind, Sec};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr,
+};
+
+/// [Sec] in the ISO 8601 duration format, like `PT1H30M0.25S` or `P1DT2H`.
+///
+/// Only the parts with a fixed length are supported:
+/// weeks are 7 days, days are 24 hours, and hours are 60 minutes.
+/// Years and months are rejected, since their length depends on the calendar.
+/// Negative durations start with a `-`, which is an extension of the standard.
+///
+/// Formatting writes days, hours, minutes and seconds, and leaves out the zero ones.
+/// Seconds are written up to nano seconds, or to the precision of the formatter.
+/// Thus, durations shorter than a nano second are lost,
+/// and the fractions of huge durations are the errors of `f64`;
+/// it can hold whole seconds exactly only up to 2^53 seconds, about 285 million years.
+/// Days are counted in a `u64`; thus, durations of 2^64 days or longer,
+/// about 5e16 years, cannot be represented, and neither can infinite and not a number durations.
+///
+/// Parsing accepts fractions in any part, with a `.` or a `,`.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Iso, Sec};
+///
+/// let dur = Sec::new(5400.25);
+/// assert_eq!("PT1H30M0.25S", dur.iso().unwrap().to_string());
+/// assert_eq!("P1DT2H", Sec::new(93600.0).iso().unwrap().to_string());
+/// assert_eq!("-PT0.5S", Sec::new(-0.5).iso().unwrap().to_string());
+/// assert_eq!("PT0S", Sec::ZERO.iso().unwrap().to_string());
+/// assert_eq!("PT1.500000000000S", format!("{:.12}", Sec::new(1.5).iso().unwrap()));
+/// assert_eq!(None, Sec::new(f64::NAN).iso());
+/// assert_eq!(None, Sec::new(1e300).iso());
+///
+/// assert_eq!(dur, "PT1H30M0.25S".parse::<Iso>().unwrap().sec());
+/// assert_eq!(Sec::DAY * 10.5, "P1,5W".parse::<Iso>().unwrap().sec());
+/// assert!("P1M".parse::<Iso>().is_err()); // a month
+///
+/// let s = "P1Y";
+/// let err = s.parse::<Iso>().unwrap_err();
+/// assert_eq!("Y", &s[err.span()]);
+/// assert_eq!(0..0, "".parse::<Iso>().unwrap_err().span());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Iso(Sec);
+
+impl Iso {
+    /// Creates if the duration can be represented, which is shorter than 2^64 days.
+    pub fn new(sec: Sec) -> Option<Self> {
+        if sec.as_f64().abs() < parts::LIMIT {
+            Some(Self(sec))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the duration.
+    pub fn sec(&self) -> Sec {
+        self.0
+    }
+}
+
+impl Sec {
+    /// Formats in the ISO 8601 duration format, if it can be represented.
+    pub fn iso(&self) -> Option<Iso> {
+        Iso::new(*self)
+    }
+}
+
+impl From<Iso> for Sec {
+    fn from(iso: Iso) -> Self {
+        iso.0
+    }
+}
+
+impl Display for Iso {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut amt = self.0.as_f64();
+        if amt < 0.0 {
+            f.write_char('-')?;
+            amt = -amt;
+        }
+        if let Some(prec) = f.precision() {
+            // Parts keep only nano seconds, and larger scales overflow.
+            let scale = float::powi(10.0, prec.min(9) as i32);
+            amt = float::round(amt * scale) / scale;
+        }
+        let parts = Sec::new(amt).parts();
+        let prec = f.precision();
+
+        f.write_char('P')?;
+        if parts.days != 0 {
+            write!(f, "{}D", parts.days)?;
+        }
+        if parts.hours == 0 && parts.mins == 0 && parts.secs == 0 && parts.nanos == 0 {
+            if parts.days == 0 {
+                f.write_str("T0")?;
+                parts.frac(f, prec)?;
+                f.write_char('S')?;
+            }
+            return Ok(());
+        }
+        f.write_char('T')?;
+        if parts.hours != 0 {
+            write!(f, "{}H", parts.hours)?;
+        }
+        if parts.mins != 0 {
+            write!(f, "{}M", parts.mins)?;
+        }
+        if parts.secs != 0 || parts.nanos != 0 {
+            write!(f, "{}", parts.secs)?;
+            parts.frac(f, prec)?;
+            f.write_char('S')?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Iso {
+    type Err = ParseSecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fail = |kind, span| Err(ParseSecError::new(kind, span));
+
+        if s.is_empty() {
+            return fail(ParseSecErrorKind::Empty, 0..0);
+        }
+        let neg = s.starts_with('-');
+        let mut at = (neg || s.starts_with('+')) as usize;
+        if !s[at..].starts_with('P') {
+            let next = s[at..].chars().next().map_or(0, char::len_utf8);
+            return fail(ParseSecErrorKind::Format, at..at + next);
+        }
+        at += 1;
+
+        let mut sum = Sec::ZERO;
+        let mut time = false;
+        let mut parts = 0;
+        // Designators that can come next, in order.
+        let mut left: &[char] = &['W', 'D', 'T', 'H', 'M', 'S'];
+
+        while at < s.len() {
+            if s[at..].starts_with('T') {
+                if time || !left.contains(&'T') {
+                    return fail(ParseSecErrorKind::Format, at..at + 1);
+                }
+                time = true;
+                left = &['H', 'M', 'S'];
+                at += 1;
+                continue;
+            }
+
+            let end = s[at..]
+                .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+                .map_or(s.len(), |end| at + end);
+            let num = at..end;
+            if num.is_empty() {
+                return fail(ParseSecErrorKind::Number, num);
+            }
+            let mut buf = Buf::<64>::new();
+            let amt = s[num.clone()]
+                .chars()
+                .try_for_each(|c| buf.write_char(if c == ',' { '.' } else { c }))
+                .ok()
+                .and_then(|_| buf.as_str().parse::<f64>().ok());
+            let amt = match amt {
+                Some(amt) => amt,
+                None => return fail(ParseSecErrorKind::Number, num),
+            };
+
+            let sym = match s[end..].chars().next() {
+                Some(sym) => sym,
+                None => return fail(ParseSecErrorKind::Missing, num),
+            };
+            let span = end..end + sym.len_utf8();
+            // Minutes come after the time designator, months before it.
+            let unit = match (sym, time) {
+                ('W', false) => Sec::DAY * 7.0,
+                ('D', false) => Sec::DAY,
+                ('H', true) => Sec::HOUR,
+                ('M', true) => Sec::MINUTE,
+                ('S', true) => Sec::ONE,
+                _ => return fail(ParseSecErrorKind::Unit, span),
+            };
+            let pos = match left.iter().position(|&c| c == sym) {
+                Some(pos) => pos,
+                None => return fail(ParseSecErrorKind::Format, span),
+            };
+            left = &left[pos + 1..];
+            // Weeks cannot be mixed with the others.
+            if sym == 'W' {
+                left = &[];
+            }
+
+            sum += unit * amt;
+            parts += 1;
+            at = span.end;
+        }
+
+        if parts == 0 || (time && left.len() == 3) {
+            return fail(ParseSecErrorKind::Format, 0..s.len());
+        }
+        Self::new(if neg { -sum } else { sum })
+            .map_or_else(|| fail(ParseSecErrorKind::Range, 0..s.len()), Ok)
+    }
+}
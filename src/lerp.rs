@@ -0,0 +1,85 @@
+use crate::Sec;
+
+/// Value that can be blended linearly with another of its kind.
+/// The heart, `Hrt`, uses this for drawing between the previous and current states.
+///
+/// Alpha is the distance from this value towards the other one:
+/// `0.0` gives this, and `1.0` gives the other.
+/// Values that cannot be blended, like booleans or identifiers,
+/// should step from one to the other.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Interpolate, Sec};
+///
+/// assert_eq!(1.5, 1.0.lerp(&2.0, 0.5));
+/// assert_eq!(Sec::new(1.5), Sec::ONE.lerp(&Sec::new(2.0), 0.5));
+/// assert_eq!([1.5, 3.0], [1.0, 2.0].lerp(&[2.0, 4.0], 0.5));
+/// assert_eq!((1.5, 3.0), (1.0, 2.0).lerp(&(2.0, 4.0), 0.5));
+/// assert_eq!(Some(1.5), Some(1.0).lerp(&Some(2.0), 0.5));
+/// assert_eq!(None, Some(1.0).lerp(&None, 0.75));
+/// ```
+pub trait Interpolate {
+    /// Blends with the other by the given alpha.
+    fn lerp(&self, other: &Self, alpha: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn lerp(&self, other: &Self, alpha: f64) -> Self {
+        self * (1.0 - alpha) + other * alpha
+    }
+}
+
+impl Interpolate for f32 {
+    fn lerp(&self, other: &Self, alpha: f64) -> Self {
+        let alpha = alpha as f32;
+        self * (1.0 - alpha) + other * alpha
+    }
+}
+
+impl Interpolate for Sec {
+    fn lerp(&self, other: &Self, alpha: f64) -> Self {
+        Sec::new(self.as_f64().lerp(&other.as_f64(), alpha))
+    }
+}
+
+impl<T: Interpolate, const N: usize> Interpolate for [T; N] {
+    fn lerp(&self, other: &Self, alpha: f64) -> Self {
+        core::array::from_fn(|i| self[i].lerp(&other[i], alpha))
+    }
+}
+
+/// Blends the values if both exist; otherwise, steps to the other in the middle.
+impl<T: Interpolate + Clone> Interpolate for Option<T> {
+    fn lerp(&self, other: &Self, alpha: f64) -> Self {
+        match (self, other) {
+            (Some(this), Some(other)) => Some(this.lerp(other, alpha)),
+            _ if alpha < 0.5 => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+impl Interpolate for () {
+    fn lerp(&self, _: &Self, _: f64) -> Self {}
+}
+
+macro_rules! tuple {
+    ($($t:ident $i:tt),+) => {
+        impl<$($t: Interpolate),+> Interpolate for ($($t,)+) {
+            fn lerp(&self, other: &Self, alpha: f64) -> Self {
+                ($(self.$i.lerp(&other.$i, alpha),)+)
+            }
+        }
+    };
+}
+
+tuple!(A 0);
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+tuple!(A 0, B 1, C 2, D 3, E 4);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
@@ -1,9 +1,40 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod float;
+mod hz;
+mod iso;
+mod lerp;
+mod moment;
+mod nanos;
 mod now;
+mod parse;
+mod parts;
+mod range;
 mod sec;
 mod timer;
+mod total;
+mod unit;
+mod warp;
+pub use hz::*;
+pub use iso::*;
+pub use lerp::*;
+pub use moment::*;
+pub use nanos::*;
 pub use now::*;
+pub use parse::*;
+pub use parts::*;
+pub use range::*;
 pub use sec::*;
 pub use timer::*;
+pub use total::*;
+pub use unit::*;
+pub use warp::*;
+
+#[cfg(feature = "derive")]
+pub use min_timer_derive::Interpolate;
+
+#[cfg(feature = "serde")]
+pub mod human;
 
 #[cfg(feature = "stat")]
 mod stat;
@@ -19,3 +50,13 @@ pub use prf::*;
 mod hrt;
 #[cfg(feature = "hrt")]
 pub use hrt::*;
+
+#[cfg(feature = "clock")]
+mod clock;
+#[cfg(feature = "clock")]
+pub use clock::*;
+
+#[cfg(feature = "hrt")]
+mod stop;
+#[cfg(feature = "hrt")]
+pub use stop::*;
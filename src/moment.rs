@@ -0,0 +1,97 @@
+use crate::Sec;
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+/// Point in time, relative to the arbitrary origin of a [Now](crate::Now).
+///
+/// Unlike [Sec], which is a duration, moments cannot be added together or scaled.
+/// Subtracting two moments gives the duration between them,
+/// and adding a duration to a moment gives another moment.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Moment, Sec};
+///
+/// let start = Moment::new(Sec::new(2.0));
+/// let end = start + Sec::new(0.5);
+///
+/// assert_eq!(Sec::new(0.5), end - start);
+/// assert_eq!(start, end - Sec::new(0.5));
+/// assert!(start < end);
+/// assert_eq!(Sec::new(2.5), end.sec());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Moment(Sec);
+
+impl Moment {
+    /// Origin of the time.
+    pub const ORIGIN: Moment = Moment(Sec::ZERO);
+
+    /// Creates at the given duration after the origin.
+    pub const fn new(sec: Sec) -> Self {
+        Self(sec)
+    }
+
+    /// Returns the duration since the origin.
+    pub const fn sec(&self) -> Sec {
+        self.0
+    }
+}
+
+impl Sub for Moment {
+    type Output = Sec;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
+impl Add<Sec> for Moment {
+    type Output = Self;
+
+    fn add(self, rhs: Sec) -> Self::Output {
+        Self(self.0 + rhs)
+    }
+}
+
+impl Add<Moment> for Sec {
+    type Output = Moment;
+
+    fn add(self, rhs: Moment) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub<Sec> for Moment {
+    type Output = Self;
+
+    fn sub(self, rhs: Sec) -> Self::Output {
+        Self(self.0 - rhs)
+    }
+}
+
+impl AddAssign<Sec> for Moment {
+    fn add_assign(&mut self, rhs: Sec) {
+        self.0 += rhs;
+    }
+}
+
+impl SubAssign<Sec> for Moment {
+    fn sub_assign(&mut self, rhs: Sec) {
+        self.0 -= rhs;
+    }
+}
+
+impl Display for Moment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
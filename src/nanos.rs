@@ -0,0 +1,171 @@
+use crate::{float, Sec};
+use core::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    time::Duration,
+};
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Time or duration in whole nano seconds.
+///
+/// Unlike [Sec], it is exact over long runs;
+/// `f64` cannot tell adjacent nano seconds apart after about 104 days.
+/// It can hold about 292 years in either direction.
+/// Keep time stamps in it, and compute [Sec] only for the differences.
+/// Arithmetic overflows like the integers do.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Nanos, Sec};
+/// use std::time::Duration;
+///
+/// let days = Nanos::try_from(Duration::from_secs(200 * 86400)).unwrap();
+/// let later = days + Nanos::new(1);
+///
+/// assert_eq!(Nanos::new(1), later - days);
+/// assert_eq!(Sec::NANO, Sec::from(later - days));
+/// assert_ne!(Sec::from(later) - Sec::from(days), Sec::NANO);
+/// assert_eq!(Duration::new(200 * 86400, 1), Duration::try_from(later).unwrap());
+/// assert!(Duration::try_from(-later).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Nanos(i64);
+
+impl Nanos {
+    /// Zero nano seconds.
+    pub const ZERO: Nanos = Nanos(0);
+    /// Longest positive amount.
+    pub const MAX: Nanos = Nanos(i64::MAX);
+    /// Longest negative amount.
+    pub const MIN: Nanos = Nanos(i64::MIN);
+
+    /// Creates from the given amount.
+    pub const fn new(amt: i64) -> Self {
+        Self(amt)
+    }
+
+    /// Returns the amount.
+    pub const fn as_i64(&self) -> i64 {
+        self.0
+    }
+
+    /// Creates from seconds, rounding to the nearest nano second.
+    /// Saturates when out of range, and not a number becomes zero.
+    pub fn from_sec(sec: Sec) -> Self {
+        let amt = sec.as_f64();
+        let whole = float::trunc(amt);
+        let secs = whole as i64;
+        let nanos = float::round((amt - whole) * 1e9) as i64;
+        Self(secs.saturating_mul(1_000_000_000).saturating_add(nanos))
+    }
+
+    /// Returns the amount in seconds, which loses precision for long durations.
+    pub fn as_sec(&self) -> Sec {
+        // Whole seconds and the rest are each exact.
+        let secs = self.0 / 1_000_000_000;
+        let nanos = self.0 % 1_000_000_000;
+        Sec::new(secs as f64 + nanos as f64 / 1e9)
+    }
+
+    /// Returns the time from the epoch to the instant,
+    /// which is negative if the instant is before the epoch.
+    /// Saturates when out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::Nanos;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let epoch = Instant::now();
+    /// let at = epoch + Duration::from_nanos(1500);
+    /// let nanos = Nanos::between(epoch, at);
+    ///
+    /// assert_eq!(Nanos::new(1500), nanos);
+    /// assert_eq!(Nanos::new(-1500), Nanos::between(at, epoch));
+    /// assert_eq!(Some(at), nanos.instant(epoch));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn between(epoch: Instant, at: Instant) -> Self {
+        match at.checked_duration_since(epoch) {
+            Some(dur) => Self::try_from(dur).unwrap_or(Self::MAX),
+            None => -Self::try_from(epoch - at).unwrap_or(Self::MAX),
+        }
+    }
+
+    /// Returns the instant that is this far from the epoch, if it can be represented.
+    #[cfg(feature = "std")]
+    pub fn instant(&self, epoch: Instant) -> Option<Instant> {
+        let dur = Duration::from_nanos(self.0.unsigned_abs());
+        if self.0 < 0 {
+            epoch.checked_sub(dur)
+        } else {
+            epoch.checked_add(dur)
+        }
+    }
+}
+
+impl From<Nanos> for Sec {
+    fn from(nanos: Nanos) -> Self {
+        nanos.as_sec()
+    }
+}
+
+impl TryFrom<Duration> for Nanos {
+    type Error = TryFromIntError;
+
+    fn try_from(dur: Duration) -> Result<Self, Self::Error> {
+        i64::try_from(dur.as_nanos()).map(Self)
+    }
+}
+
+impl TryFrom<Nanos> for Duration {
+    type Error = TryFromIntError;
+
+    fn try_from(nanos: Nanos) -> Result<Self, Self::Error> {
+        u64::try_from(nanos.0).map(Duration::from_nanos)
+    }
+}
+
+impl Add for Nanos {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Nanos {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Nanos {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Nanos {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Nanos {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
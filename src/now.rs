@@ -1,20 +1,51 @@
-use crate::Sec;
+use crate::{Moment, Nanos, Sec};
+use core::cell::Cell;
+#[cfg(feature = "std")]
 use std::time::Instant;
 
 /// Resource that has a time relative to an arbitrary moment.
 pub trait Now {
     /// Returns current the time.
-    fn now(&self) -> Sec;
+    fn now(&self) -> Moment;
+
+    /// Returns the current time in whole nano seconds.
+    /// Rounds [now](Now::now) by default; resources that count
+    /// nano seconds should return them exactly.
+    fn nanos(&self) -> Nanos {
+        Nanos::from_sec(self.now().sec())
+    }
 }
 
-/// [Now] that uses [standard library](std::time::Instant).
+/// [Now] that uses [standard library](std::time::Instant), with the `std` feature.
 /// Standard library uses [two integers](std::time::Duration), thus you pay for
 /// conversion to `f64` all the time.
+/// Gives exact [nano seconds](Now::nanos), which do not lose precision over long runs.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Nanos, Now, Std};
+///
+/// let now = Std::new();
+/// let start = now.nanos();
+/// let end = now.nanos();
+///
+/// assert!(end - start >= Nanos::ZERO);
+/// ```
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub struct Std {
     start: Instant,
 }
 
+#[cfg(feature = "std")]
+impl Default for Std {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
 impl Std {
     /// Creates from the current instant.
     pub fn new() -> Self {
@@ -24,8 +55,70 @@ impl Std {
     }
 }
 
+#[cfg(feature = "std")]
 impl Now for Std {
-    fn now(&self) -> Sec {
-        Sec::from(self.start.elapsed())
+    fn now(&self) -> Moment {
+        Moment::new(Sec::from(self.start.elapsed()))
+    }
+
+    fn nanos(&self) -> Nanos {
+        Nanos::between(self.start, Instant::now())
+    }
+}
+
+/// [Now] that only moves when it is told to.
+/// Time can be changed through a shared reference,
+/// thus it can be moved while timers are borrowing it.
+/// Useful for deterministic tests.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Manual, Moment, Now, Sec, Timer};
+///
+/// let now = Manual::new();
+/// let timer = Timer::new(&now);
+///
+/// assert_eq!(Moment::ORIGIN, now.now());
+/// assert!(timer < Sec::ONE);
+///
+/// now.advance(Sec::ONE);
+/// assert_eq!(Sec::ONE, timer.elapsed());
+///
+/// now.set(Moment::new(Sec::MINUTE));
+/// assert!(timer == Sec::MINUTE);
+/// ```
+#[derive(Debug, Default)]
+pub struct Manual {
+    time: Cell<Moment>,
+}
+
+impl Manual {
+    /// Creates at the origin.
+    pub const fn new() -> Self {
+        Self::at(Moment::ORIGIN)
+    }
+
+    /// Creates at the given time.
+    pub const fn at(time: Moment) -> Self {
+        Self {
+            time: Cell::new(time),
+        }
+    }
+
+    /// Moves the time forward by the given amount.
+    pub fn advance(&self, dur: Sec) {
+        self.time.set(self.time.get() + dur);
+    }
+
+    /// Moves the time to the given moment.
+    pub fn set(&self, time: Moment) {
+        self.time.set(time);
+    }
+}
+
+impl Now for Manual {
+    fn now(&self) -> Moment {
+        self.time.get()
     }
 }
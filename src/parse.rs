@@ -0,0 +1,173 @@
+use crate::{float, unit::Buf, Sec, Unit};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter, Write},
+    ops::Range,
+};
+
+/// Error from parsing a [Sec] or a [Hz](crate::Hz).
+/// Knows which part of the string failed.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{ParseSecErrorKind, Sec};
+///
+/// let s = "1h 30 parsecs";
+/// let err = s.parse::<Sec>().unwrap_err();
+///
+/// assert_eq!(ParseSecErrorKind::Unit, err.kind());
+/// assert_eq!("parsecs", &s[err.span()]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSecError {
+    kind: ParseSecErrorKind,
+    span: Range<usize>,
+}
+
+/// Reason of a [ParseSecError].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseSecErrorKind {
+    /// There is nothing to parse.
+    Empty,
+    /// Amount is not a number.
+    Number,
+    /// Amount is not followed by a unit, while there are more amounts.
+    Missing,
+    /// Unit is not known.
+    Unit,
+    /// Parts are not in the expected order or shape, like in an ISO 8601 duration.
+    Format,
+    /// Duration cannot be represented, like an ISO 8601 duration that is too long.
+    Range,
+}
+
+impl ParseSecError {
+    pub(crate) fn new(kind: ParseSecErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Returns the reason.
+    pub fn kind(&self) -> ParseSecErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the failed part in the parsed string.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl Display for ParseSecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let reason = match self.kind {
+            ParseSecErrorKind::Empty => "nothing to parse",
+            ParseSecErrorKind::Number => "invalid amount",
+            ParseSecErrorKind::Missing => "missing unit",
+            ParseSecErrorKind::Unit => "unknown unit",
+            ParseSecErrorKind::Format => "invalid format",
+            ParseSecErrorKind::Range => "out of range",
+        };
+        write!(f, "{} at {}..{}", reason, self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseSecError {}
+
+/// Parses amounts with units, which are summed up.
+/// A lone amount without a unit is in seconds.
+pub(crate) fn sec(s: &str) -> Result<Sec, ParseSecError> {
+    if let Ok(amt) = s.trim().parse::<f64>() {
+        return Ok(Sec::new(amt));
+    }
+
+    let mut at = skip(s, 0, char::is_whitespace);
+    let neg = s[at..].starts_with('-');
+    if neg || s[at..].starts_with('+') {
+        at += 1;
+    }
+
+    let mut sum = Sec::ZERO;
+    let mut terms = 0;
+    loop {
+        at = skip(s, at, char::is_whitespace);
+        if at == s.len() {
+            break;
+        }
+
+        let num = at..amount(s, at);
+        let amt = s[num.clone()]
+            .parse::<f64>()
+            .map_err(|_| ParseSecError::new(ParseSecErrorKind::Number, num.clone()))?;
+        at = skip(s, num.end, char::is_whitespace);
+
+        let sym = at..skip(s, at, char::is_alphabetic);
+        if sym.is_empty() {
+            return Err(ParseSecError::new(ParseSecErrorKind::Missing, num));
+        }
+        let unit = Unit::from_symbol(&s[sym.clone()])
+            .ok_or_else(|| ParseSecError::new(ParseSecErrorKind::Unit, sym.clone()))?;
+        at = sym.end;
+
+        sum += exact(&s[num], amt, unit);
+        terms += 1;
+    }
+
+    if terms == 0 {
+        return Err(ParseSecError::new(ParseSecErrorKind::Empty, 0..s.len()));
+    }
+    Ok(if neg { -sum } else { sum })
+}
+
+/// Returns the end of the characters that pass the test from the start.
+fn skip(s: &str, start: usize, test: impl Fn(char) -> bool) -> usize {
+    s[start..]
+        .find(|c| !test(c))
+        .map_or(s.len(), |end| start + end)
+}
+
+/// Returns the end of the amount from the start.
+fn amount(s: &str, start: usize) -> usize {
+    for word in ["inf", "NaN"] {
+        if s[start..].starts_with(word) {
+            return start + word.len();
+        }
+    }
+    let mut end = skip(s, start, |c| c.is_ascii_digit() || c == '.');
+    // Exponent; no unit starts with an e.
+    if let Some(rest) = s[end..].strip_prefix(['e', 'E']) {
+        let sign = rest.starts_with(['+', '-']) as usize;
+        let digits = skip(s, end + 1 + sign, |c| c.is_ascii_digit());
+        if digits > end + 1 + sign {
+            end = digits;
+        }
+    }
+    end
+}
+
+/// Converts decimal units by moving the exponent, which is exact;
+/// thus, formatted durations turn back to the same value.
+fn exact(num: &str, amt: f64, unit: Unit) -> Sec {
+    match unit.pow() {
+        Some(pow) => Sec::new(shift(num, amt, pow)),
+        None => Sec::from_unit(amt, unit),
+    }
+}
+
+/// Multiplies the parsed amount by the power of ten by moving the exponent of the number,
+/// which is exact unlike the multiplication.
+pub(crate) fn shift(num: &str, amt: f64, pow: i32) -> f64 {
+    if !num.contains(['e', 'E', 'i', 'N']) {
+        let mut buf = Buf::<512>::new();
+        if write!(buf, "{}e{}", num, pow).is_ok() {
+            if let Ok(amt) = buf.as_str().parse() {
+                return amt;
+            }
+        }
+    }
+    if pow < 0 {
+        amt / float::powi(10.0, -pow)
+    } else {
+        amt * float::powi(10.0, pow)
+    }
+}
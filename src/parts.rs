@@ -0,0 +1,198 @@
+use crate::{float, Sec};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Seconds in 2^64 days; the days of shorter durations fit in a `u64`.
+pub(crate) const LIMIT: f64 = 18446744073709551616.0 * 86400.0;
+
+/// [Sec] split into days, hours, minutes, seconds and nano seconds.
+///
+/// Parts are of the magnitude; negative durations are marked with `neg`.
+/// Splitting rounds to the nearest nano second,
+/// so that the seconds do not lose a nano second to the error of `f64`.
+/// Durations of 2^64 days or longer, including the infinite ones, saturate to the [longest](Parts::MAX) parts,
+/// and not a number has none.
+///
+/// Formats like a clock, as `02:15:07.25`, with the days in front if any, as `3d 04:00:00`.
+/// Seconds are written up to nano seconds, or cut to the precision of the formatter;
+/// round with [round_to](Sec::round_to) first to round instead.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Parts, Sec};
+///
+/// let dur = Sec::new(8107.25);
+/// let parts = dur.parts();
+///
+/// assert_eq!((2, 15, 7), (parts.hours, parts.mins, parts.secs));
+/// assert_eq!(250_000_000, parts.nanos);
+/// assert_eq!(dur, Sec::from(parts));
+/// assert_eq!("02:15:07.25", parts.to_string());
+/// assert_eq!("02:15:07.250", format!("{:.3}", parts));
+///
+/// let parts = (-(Sec::DAY * 3.0 + Sec::HOUR * 4.0)).parts();
+/// assert!(parts.neg);
+/// assert_eq!("-3d 04:00:00", parts.to_string());
+///
+/// assert_eq!(Parts::MAX, Sec::new(f64::INFINITY).parts());
+/// assert_eq!(Parts::MAX, Sec::new(1e25).parts());
+/// assert!(Sec::new(f64::NAN).parts().is_zero());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Parts {
+    /// Whether the duration is negative.
+    pub neg: bool,
+    /// Whole days.
+    pub days: u64,
+    /// Hours in the day, less than 24.
+    pub hours: u8,
+    /// Minutes in the hour, less than 60.
+    pub mins: u8,
+    /// Seconds in the minute, less than 60.
+    pub secs: u8,
+    /// Nano seconds in the second, less than a billion.
+    pub nanos: u32,
+}
+
+impl Parts {
+    /// Longest parts, which is a nano second short of 2^64 days.
+    pub const MAX: Parts = Parts {
+        neg: false,
+        days: u64::MAX,
+        hours: 23,
+        mins: 59,
+        secs: 59,
+        nanos: 999_999_999,
+    };
+
+    /// Whether all the parts are zero, regardless of the sign.
+    pub fn is_zero(&self) -> bool {
+        self.days == 0 && self.hours == 0 && self.mins == 0 && self.secs == 0 && self.nanos == 0
+    }
+
+    /// Writes the fraction of the seconds with the dot, if there is any.
+    /// Without a precision trailing zeros are left out.
+    pub(crate) fn frac(&self, f: &mut impl Write, prec: Option<usize>) -> fmt::Result {
+        match prec {
+            Some(0) => Ok(()),
+            Some(prec) if prec <= 9 => {
+                write!(
+                    f,
+                    ".{:0p$}",
+                    self.nanos / 10u32.pow(9 - prec as u32),
+                    p = prec
+                )
+            }
+            Some(prec) => write!(f, ".{:09}{:0p$}", self.nanos, 0, p = prec - 9),
+            None if self.nanos == 0 => Ok(()),
+            None => {
+                let mut digits = self.nanos;
+                let mut prec = 9;
+                while digits.is_multiple_of(10) {
+                    digits /= 10;
+                    prec -= 1;
+                }
+                write!(f, ".{:0p$}", digits, p = prec)
+            }
+        }
+    }
+}
+
+impl Sec {
+    /// Splits into days, hours, minutes, seconds and nano seconds.
+    pub fn parts(&self) -> Parts {
+        let neg = self.as_f64() < 0.0;
+        let amt = self.as_f64().abs();
+        if amt >= LIMIT {
+            return Parts { neg, ..Parts::MAX };
+        }
+        let mut whole = float::trunc(amt);
+        let mut nanos = float::round((amt - whole) * 1e9);
+        if nanos >= 1e9 {
+            nanos -= 1e9;
+            whole += 1.0;
+        }
+        let secs = whole % 60.0;
+        let mins = (whole - secs) / 60.0;
+        Parts {
+            neg,
+            days: float::floor(mins / 1440.0) as u64,
+            hours: (float::floor(mins / 60.0) % 24.0) as u8,
+            mins: (mins % 60.0) as u8,
+            secs: secs as u8,
+            nanos: nanos as u32,
+        }
+    }
+
+    /// Rounds to the nearest multiple of the granularity, like [Sec::MILLI].
+    /// Halfway cases are rounded away from zero.
+    /// Returns the same duration if the granularity is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::Sec;
+    ///
+    /// assert_eq!(Sec::new(1.235), Sec::new(1.23456).round_to(Sec::MILLI));
+    /// assert_eq!(Sec::new(-1.235), Sec::new(-1.23456).round_to(Sec::MILLI));
+    /// assert_eq!(Sec::MINUTE * 2.0, Sec::new(90.0).round_to(Sec::MINUTE));
+    /// ```
+    pub fn round_to(self, gran: Sec) -> Sec {
+        self.to_gran(gran, float::round)
+    }
+
+    /// Truncates towards zero to a multiple of the granularity, like [Sec::MILLI].
+    /// Returns the same duration if the granularity is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::Sec;
+    ///
+    /// assert_eq!(Sec::new(1.234), Sec::new(1.23456).trunc_to(Sec::MILLI));
+    /// assert_eq!(Sec::new(-1.234), Sec::new(-1.23456).trunc_to(Sec::MILLI));
+    /// assert_eq!(Sec::MINUTE, Sec::new(90.0).trunc_to(Sec::MINUTE));
+    /// ```
+    pub fn trunc_to(self, gran: Sec) -> Sec {
+        self.to_gran(gran, float::trunc)
+    }
+
+    fn to_gran(self, gran: Sec, op: fn(f64) -> f64) -> Sec {
+        let (amt, gran) = (self.as_f64(), gran.as_f64().abs());
+        if gran == 0.0 {
+            return self;
+        }
+        // Scaling by a whole inverse, like a thousand for milli seconds, is exact.
+        let inv = gran.recip();
+        if gran < 1.0 && float::fract(inv) == 0.0 {
+            Sec::new(op(amt * inv) / inv)
+        } else {
+            Sec::new(op(amt / gran) * gran)
+        }
+    }
+}
+
+impl From<Parts> for Sec {
+    fn from(parts: Parts) -> Self {
+        let whole = parts.days as f64 * 86400.0
+            + parts.hours as f64 * 3600.0
+            + parts.mins as f64 * 60.0
+            + parts.secs as f64;
+        let amt = whole + parts.nanos as f64 / 1e9;
+        Sec::new(if parts.neg { -amt } else { amt })
+    }
+}
+
+impl Display for Parts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.neg {
+            f.write_char('-')?;
+        }
+        if self.days != 0 {
+            write!(f, "{}d ", self.days)?;
+        }
+        write!(f, "{:02}:{:02}:{:02}", self.hours, self.mins, self.secs)?;
+        let prec = f.precision();
+        self.frac(f, prec)
+    }
+}
@@ -1,30 +1,35 @@
 use crate::{now::Now, Sec, Timer};
-use std::ops::AddAssign;
+use core::ops::AddAssign;
 
 /// Smart pointer, which automaticly accumulates the time it takes to drop.
 /// It can be used to profile whole scopes just by creating.
 ///
+/// The profiler must be bound to a name; otherwise, it is dropped right away
+/// and measures nothing. Use [prf!](crate::prf) for binding it to the enclosing scope,
+/// or [Prf::scope] for profiling a closure.
+///
 /// # Example
 ///
 /// ```
-/// use min_timer::{Std, Prf, Stat};
+/// use min_timer::{prf, Manual, Prf, Stat};
 ///
-/// fn subroutine() {}
+/// fn subroutine() -> u32 { 42 }
 ///
 /// let mut stat = Stat::new();
-/// let now = Std::new();
+/// let now = Manual::new();
 ///
-/// { let _ = Prf::new(&now, &mut stat); subroutine(); }
-/// { let _ = Prf::new(&now, &mut stat); subroutine(); }
+/// { let _prf = Prf::new(&now, &mut stat); subroutine(); }
+/// { prf!(&now, &mut stat); subroutine(); }
 ///
 /// stat.refresh();
 ///
-/// { let _ = Prf::new(&now, &mut stat); subroutine(); }
-/// { let _ = Prf::new(&now, &mut stat); subroutine(); }
+/// assert_eq!(42, Prf::scope(&now, &mut stat, subroutine));
+/// assert_eq!(42, Prf::scope(&now, &mut stat, || subroutine()));
 ///
 /// assert_eq!(4, stat.count());
 /// assert_eq!(2, stat.rate());
 /// ```
+#[must_use = "the profiler measures until it is dropped; bind it with `let _prf = ...` or use `prf!`"]
 pub struct Prf<'a, T: Now, U: AddAssign<Sec>> {
     timer: Timer<'a, T>,
     acc: &'a mut U,
@@ -38,6 +43,12 @@ impl<'a, T: Now, U: AddAssign<Sec>> Prf<'a, T, U> {
             acc,
         }
     }
+
+    /// Profiles the given closure and returns its result.
+    pub fn scope<V>(now: &'a T, acc: &'a mut U, f: impl FnOnce() -> V) -> V {
+        let _prf = Self::new(now, acc);
+        f()
+    }
 }
 
 impl<'a, T: Now, U: AddAssign<Sec>> Drop for Prf<'a, T, U> {
@@ -45,3 +56,30 @@ impl<'a, T: Now, U: AddAssign<Sec>> Drop for Prf<'a, T, U> {
         *self.acc += self.timer.elapsed();
     }
 }
+
+/// Profiles the rest of the enclosing scope.
+///
+/// Creates a [Prf] that lives until the end of the scope it is called in.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{prf, Manual, Sec, Stat};
+///
+/// let mut stat = Stat::new();
+/// let now = Manual::new();
+///
+/// {
+///     prf!(&now, &mut stat);
+///     now.advance(Sec::MILLI);
+/// }
+///
+/// assert_eq!(1, stat.count());
+/// assert_eq!(Sec::MILLI, stat.dur());
+/// ```
+#[macro_export]
+macro_rules! prf {
+    ($now:expr, $acc:expr) => {
+        let _prf = $crate::Prf::new($now, $acc);
+    };
+}
@@ -0,0 +1,142 @@
+use crate::Sec;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+/// Error from a [Sec] that is out of the range of the target.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Sec, SecRangeErrorKind};
+/// use std::time::Duration;
+///
+/// let err = Duration::try_from(Sec::new(-1e-9)).unwrap_err();
+/// assert_eq!(SecRangeErrorKind::Negative, err.kind());
+///
+/// let err = Sec::try_new(f64::NAN).unwrap_err();
+/// assert_eq!(SecRangeErrorKind::NotANumber, err.kind());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecRangeError {
+    kind: SecRangeErrorKind,
+}
+
+/// Reason of a [SecRangeError].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SecRangeErrorKind {
+    /// Amount is not a number.
+    NotANumber,
+    /// Amount is infinite.
+    Infinite,
+    /// Amount is less than zero.
+    Negative,
+    /// Amount is too big for the target.
+    Overflow,
+}
+
+impl SecRangeError {
+    fn new(kind: SecRangeErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// Returns the reason.
+    pub fn kind(&self) -> SecRangeErrorKind {
+        self.kind
+    }
+}
+
+impl Display for SecRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            SecRangeErrorKind::NotANumber => "duration is not a number",
+            SecRangeErrorKind::Infinite => "duration is infinite",
+            SecRangeErrorKind::Negative => "duration is negative",
+            SecRangeErrorKind::Overflow => "duration is too long",
+        })
+    }
+}
+
+impl Error for SecRangeError {}
+
+impl Sec {
+    /// Creates from the given amount, if it is finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::Sec;
+    ///
+    /// assert_eq!(Ok(Sec::new(-2.5)), Sec::try_new(-2.5));
+    /// assert!(Sec::try_new(f64::INFINITY).is_err());
+    /// ```
+    pub fn try_new(amt: f64) -> Result<Self, SecRangeError> {
+        if amt.is_nan() {
+            Err(SecRangeError::new(SecRangeErrorKind::NotANumber))
+        } else if amt.is_infinite() {
+            Err(SecRangeError::new(SecRangeErrorKind::Infinite))
+        } else {
+            Ok(Self::new(amt))
+        }
+    }
+
+    /// Whether the amount is neither infinite nor not a number.
+    pub fn is_finite(&self) -> bool {
+        self.as_f64().is_finite()
+    }
+
+    /// Whether the amount is less than zero.
+    /// Negative zero and not a number are not negative.
+    pub fn is_negative(&self) -> bool {
+        self.as_f64() < 0.0
+    }
+
+    /// Converts to a [Duration], if it is in its range.
+    pub fn checked_duration(&self) -> Option<Duration> {
+        Duration::try_from(*self).ok()
+    }
+
+    /// Converts to a [Duration], clamping to its range.
+    /// Negative durations and not a number become zero,
+    /// and too long ones become the longest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::Sec;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(250), Sec::new(0.25).saturating_duration());
+    /// assert_eq!(Duration::ZERO, Sec::new(-0.25).saturating_duration());
+    /// assert_eq!(Duration::MAX, Sec::new(f64::INFINITY).saturating_duration());
+    /// ```
+    pub fn saturating_duration(&self) -> Duration {
+        match Duration::try_from(*self) {
+            Ok(dur) => dur,
+            Err(err) => match err.kind {
+                SecRangeErrorKind::NotANumber | SecRangeErrorKind::Negative => Duration::ZERO,
+                SecRangeErrorKind::Infinite | SecRangeErrorKind::Overflow => Duration::MAX,
+            },
+        }
+    }
+}
+
+impl TryFrom<Sec> for Duration {
+    type Error = SecRangeError;
+
+    fn try_from(sec: Sec) -> Result<Self, Self::Error> {
+        let amt = sec.as_f64();
+        let kind = if amt.is_nan() {
+            SecRangeErrorKind::NotANumber
+        } else if sec.is_negative() {
+            SecRangeErrorKind::Negative
+        } else if amt.is_infinite() {
+            SecRangeErrorKind::Infinite
+        } else {
+            SecRangeErrorKind::Overflow
+        };
+        Duration::try_from_secs_f64(amt).map_err(|_| SecRangeError::new(kind))
+    }
+}
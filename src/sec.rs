@@ -1,7 +1,8 @@
-use std::{
-    fmt::Display,
-    num::ParseFloatError,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+use crate::{float, parse, parts, unit, ParseSecError, Unit};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
     str::FromStr,
     time::Duration,
 };
@@ -25,7 +26,9 @@ use std::{
 ///
 /// ## 2 Standard Library Duration
 ///
-/// You can convert to and from [std::time::Duration].
+/// You can convert to and from [Duration](core::time::Duration).
+/// Converting to it fails for negative, too long or not finite seconds;
+/// see [SecRangeError](crate::SecRangeError) and [saturating_duration](Sec::saturating_duration).
 ///
 /// ```
 /// use min_timer::Sec;
@@ -36,6 +39,8 @@ use std::{
 /// let sec = Sec::from(dur);
 ///
 /// assert_eq!(Sec::from(A), sec);
+/// assert_eq!(Ok(dur), Duration::try_from(sec));
+/// assert!(Duration::try_from(-sec).is_err());
 /// ```
 ///
 /// ## 3 Addition & Subtraction
@@ -61,6 +66,9 @@ use std::{
 ///
 /// You can only multiply or divide seconds with a scalar.
 /// You cannot multiply two seconds or divide a scalar to a second.
+/// Dividing two seconds gives their ratio, and the remainder is in seconds.
+/// Use [div_euclid](Sec::div_euclid) to count the whole periods in a duration.
+/// Seconds can be summed up from an iterator.
 ///
 /// ```
 /// use min_timer::Sec;
@@ -73,6 +81,14 @@ use std::{
 ///
 /// assert_eq!(Sec::new(A * C), a * C);
 /// assert_eq!(Sec::new(B / C), b / C);
+/// assert_eq!(B / A, b / a);
+/// assert_eq!(Sec::new(B % A), b % a);
+///
+/// assert_eq!(5.0, Sec::new(5.5).div_euclid(Sec::ONE));
+/// assert_eq!(-6.0, Sec::new(-5.5).div_euclid(Sec::ONE));
+/// assert_eq!(Sec::new(0.5), Sec::new(-5.5).rem_euclid(Sec::ONE));
+///
+/// assert_eq!(Sec::new(A + B), [a, b].iter().sum());
 /// ```
 ///
 /// ## 5 Assignment Operations
@@ -105,18 +121,57 @@ use std::{
 ///
 /// ## 6 Parsing & Formatting
 ///
-/// You can parse a string like a `f64`.
-/// And you can format to a string with a " s" at the end.
+/// You can parse a string like a `f64`, which is in seconds.
+/// Amounts can have units, like `250ms`, `1.5 s` or `1h30m`; these are summed up.
+/// See [Unit::from_symbol] for the units.
+/// And you can format to a string in the most fitting unit,
+/// from nano seconds to days; precision and width are of the formatter.
+/// Alternate form splits to days, hours, minutes and seconds;
+/// durations of 2^64 days or longer are written in seconds.
+/// Use [display](Sec::display) to pick a unit.
+///
+/// Only the plain form without a precision turns back to the same value when parsed.
+/// Alternate form rounds to nano seconds,
+/// and sums the parts back with the error of `f64` for very long durations.
 ///
 /// ```
-/// use min_timer::Sec;
+/// use min_timer::{Sec, Unit};
 /// const A: f64 = 123.456;
 ///
 /// let g = format!("{}", A);
 /// let h: Sec = g.parse().unwrap();
 ///
 /// assert_eq!(Sec::from(A), h);
-/// assert_eq!(format!("{} s", A), format!("{}", h));
+/// assert_eq!("123.456 s", format!("{}", h));
+/// assert_eq!("2.06 min", format!("{:.2}", h));
+/// assert_eq!("2m 03.456s", format!("{:#}", h));
+/// assert_eq!("0.123456 ks", format!("{}", h.display(Unit::Kilo)));
+///
+/// assert_eq!("12.345 µs", format!("{}", Sec::new(0.000012345)));
+/// assert_eq!("[  250 ms]", format!("[{:>8}]", Sec::new(0.25)));
+/// assert_eq!("25 h", format!("{}", Sec::new(90000.0)));
+/// assert_eq!("1h 02m 03.5s", format!("{:#}", Sec::new(3723.5)));
+/// assert_eq!("-1d 00h 00m 00.00s", format!("{:#.2}", -Sec::DAY));
+/// assert_eq!("1.500000000000s", format!("{:#.12}", Sec::new(1.5)));
+///
+/// assert_eq!(Sec::new(0.25), "250ms".parse().unwrap());
+/// assert_eq!(Sec::new(5400.0), "1h30m".parse().unwrap());
+/// assert_eq!(Sec::new(-20e-6), " -20 us ".parse().unwrap());
+/// assert!("1h 30".parse::<Sec>().is_err());
+///
+/// // Formatting turns back to the same value.
+/// let i = Sec::new(0.000012345);
+/// assert_eq!(i, format!("{}", i).parse().unwrap());
+///
+/// // Alternate form does not.
+/// let j = Sec::new(59.9999999999);
+/// assert_eq!(j, format!("{}", j).parse().unwrap());
+/// assert_eq!("1m 00s", format!("{:#}", j));
+/// assert_eq!(Sec::MINUTE, format!("{:#}", j).parse().unwrap());
+///
+/// let k = Sec::new(1e20);
+/// assert_eq!("1157407407407407d 12h 12m 40s", format!("{:#}", k));
+/// assert_ne!(k, format!("{:#}", k).parse().unwrap());
 /// ```
 ///
 /// ## 7 Constants
@@ -125,6 +180,8 @@ use std::{
 /// Furthermore; minutes, hours and days also exist.
 /// If you find a use case to these please share it with me!
 ///
+/// Operators cannot be used in constants; there are `const` methods for them.
+///
 /// ```
 /// use min_timer::Sec;
 ///
@@ -132,19 +189,30 @@ use std::{
 /// let same = Sec::new(0.050);
 ///
 /// assert_eq!(duration, same);
+///
+/// const TICK: Sec = Sec::MILLI.mul_f64(16.0);
+/// const TIMEOUT: Sec = Sec::from_minutes(1.5).add(Sec::from_millis(250.0));
+///
+/// assert_eq!(Sec::new(0.016), TICK);
+/// assert_eq!(Sec::new(90.25), TIMEOUT);
 /// ```
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Sec(f64);
 
 impl Sec {
     /// One giga second, Gs.
     pub const GIGA: Sec = Sec::new(1e9);
     /// One day, d.
-    pub const DAY: Sec = Sec::new(24.0 * Sec::HOUR.0);
+    pub const DAY: Sec = Sec::HOUR.mul_f64(24.0);
     /// One mega second, Ms.
     pub const MEGA: Sec = Sec::new(1e6);
     /// One hour, h.
-    pub const HOUR: Sec = Sec::new(60.0 * Sec::MINUTE.0);
+    pub const HOUR: Sec = Sec::MINUTE.mul_f64(60.0);
     /// One kilo second, ks.
     pub const KILO: Sec = Sec::new(1e3);
     /// One minute, min.
@@ -169,6 +237,67 @@ impl Sec {
     pub const fn as_f64(&self) -> f64 {
         self.0
     }
+
+    /// Creates from the given amount of nano seconds.
+    pub const fn from_nanos(amt: f64) -> Self {
+        Self(amt / 1e9)
+    }
+
+    /// Creates from the given amount of micro seconds.
+    pub const fn from_micros(amt: f64) -> Self {
+        Self(amt / 1e6)
+    }
+
+    /// Creates from the given amount of milli seconds.
+    pub const fn from_millis(amt: f64) -> Self {
+        Self(amt / 1e3)
+    }
+
+    /// Creates from the given amount of minutes.
+    pub const fn from_minutes(amt: f64) -> Self {
+        Self(amt * Self::MINUTE.0)
+    }
+
+    /// Creates from the given amount of hours.
+    pub const fn from_hours(amt: f64) -> Self {
+        Self(amt * Self::HOUR.0)
+    }
+
+    /// Creates from the given amount of days.
+    pub const fn from_days(amt: f64) -> Self {
+        Self(amt * Self::DAY.0)
+    }
+
+    /// Adds, like the operator, in constants.
+    pub const fn add(self, rhs: Sec) -> Sec {
+        Self(self.0 + rhs.0)
+    }
+
+    /// Subtracts, like the operator, in constants.
+    pub const fn sub(self, rhs: Sec) -> Sec {
+        Self(self.0 - rhs.0)
+    }
+
+    /// Multiplies with a scalar, like the operator, in constants.
+    pub const fn mul_f64(self, rhs: f64) -> Sec {
+        Self(self.0 * rhs)
+    }
+
+    /// Divides by a scalar, like the operator, in constants.
+    pub const fn div_f64(self, rhs: f64) -> Sec {
+        Self(self.0 / rhs)
+    }
+
+    /// Finds the amount of whole periods in the duration, like `f64::div_euclid`.
+    /// Leaves the [rem_euclid](Sec::rem_euclid), which is never negative.
+    pub fn div_euclid(self, period: Sec) -> f64 {
+        float::div_euclid(self.0, period.0)
+    }
+
+    /// Finds the duration left after the whole periods, like `f64::rem_euclid`.
+    pub fn rem_euclid(self, period: Sec) -> Sec {
+        Self(float::rem_euclid(self.0, period.0))
+    }
 }
 
 impl From<f64> for Sec {
@@ -189,12 +318,6 @@ impl From<Duration> for Sec {
     }
 }
 
-impl From<Sec> for Duration {
-    fn from(sec: Sec) -> Self {
-        Self::from_secs_f64(sec.0)
-    }
-}
-
 impl Add for Sec {
     type Output = Self;
 
@@ -259,6 +382,40 @@ impl DivAssign<f64> for Sec {
     }
 }
 
+impl Div for Sec {
+    type Output = f64;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.0 / rhs.0
+    }
+}
+
+impl Rem for Sec {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl RemAssign for Sec {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.0 %= rhs.0;
+    }
+}
+
+impl Sum for Sec {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|sec| sec.0).sum())
+    }
+}
+
+impl<'a> Sum<&'a Sec> for Sec {
+    fn sum<I: Iterator<Item = &'a Sec>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl Neg for Sec {
     type Output = Sec;
 
@@ -268,15 +425,91 @@ impl Neg for Sec {
 }
 
 impl FromStr for Sec {
-    type Err = ParseFloatError;
+    type Err = ParseSecError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.parse::<f64>()?))
+        parse::sec(s)
     }
 }
 
 impl Display for Sec {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} s", self.0)
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let compound = Compound {
+                sec: *self,
+                prec: f.precision(),
+            };
+            return unit::pad(f, format_args!("{}", compound));
+        }
+        let mut unit = Unit::fit(*self);
+        if f.precision().is_none() && unit > Unit::One {
+            // Amount must be short and turn back to the same value.
+            unit = [Unit::Day, Unit::Hour, Unit::Minute]
+                .into_iter()
+                .filter(|&long| long <= unit)
+                .find(|&long| {
+                    let amt = self.as_unit(long);
+                    float::fract(amt * 1e3) == 0.0 && Sec::from_unit(amt, long) == *self
+                })
+                .unwrap_or(Unit::One);
+        }
+        self.display(unit).fmt(f)
+    }
+}
+
+/// [Sec] that is formatted in days, hours, minutes and seconds.
+struct Compound {
+    sec: Sec,
+    prec: Option<usize>,
+}
+
+impl Display for Compound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut amt = self.sec.0;
+        // Days would saturate.
+        if amt.is_nan() || amt.abs() >= parts::LIMIT {
+            return write!(f, "{} s", amt);
+        }
+        if amt < 0.0 {
+            f.write_char('-')?;
+            amt = -amt;
+        }
+        if amt != 0.0 && amt < 1.0 {
+            let sec = Sec(amt);
+            let unit = Unit::fit(sec);
+            sec.display(unit).amt(f, self.prec)?;
+            return f.write_str(unit.symbol());
+        }
+        if let Some(prec) = self.prec {
+            // Round first, so that the seconds do not round up to 60.
+            // Parts keep only nano seconds, and larger scales overflow.
+            let scale = float::powi(10.0, prec.min(9) as i32);
+            amt = float::round(amt * scale) / scale;
+        }
+
+        let parts = Sec(amt).parts();
+        let mut lead = true;
+        for (part, symbol) in [
+            (parts.days, 'd'),
+            (parts.hours as u64, 'h'),
+            (parts.mins as u64, 'm'),
+        ] {
+            if lead && part == 0 {
+                continue;
+            }
+            if lead {
+                write!(f, "{}{} ", part, symbol)?;
+            } else {
+                write!(f, "{:02}{} ", part, symbol)?;
+            }
+            lead = false;
+        }
+        if lead {
+            write!(f, "{}", parts.secs)?;
+        } else {
+            write!(f, "{:02}", parts.secs)?;
+        }
+        parts.frac(f, self.prec)?;
+        f.write_char('s')
     }
 }
@@ -1,5 +1,5 @@
-use crate::Sec;
-use std::ops::AddAssign;
+use crate::{Hz, Sec};
+use core::ops::AddAssign;
 
 /// Time statistics of a subroutine.
 ///
@@ -23,6 +23,7 @@ use std::ops::AddAssign;
 /// assert_eq!(1, s.rate());
 /// ```
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stat {
     total: Sec,
     count: u64,
@@ -58,14 +59,19 @@ impl Stat {
         self.rate
     }
 
+    /// Returns the total duration of all the calls.
+    pub fn total(&self) -> Sec {
+        self.total
+    }
+
     /// Finds the average duration of the subroutine.
     pub fn dur(&self) -> Sec {
         self.total / self.count as f64
     }
 
-    /// Finds the average rate.
-    pub fn avg_rate(&self) -> f64 {
-        self.count as f64 / self.cycles as f64
+    /// Finds the average rate, which is per second when the cycles are a second long.
+    pub fn avg_rate(&self) -> Hz {
+        Hz::new(self.count as f64 / self.cycles as f64)
     }
 
     /// Means the end of a cycle.
@@ -77,6 +83,13 @@ impl Stat {
         self.rate = 0;
         self.cycles += 1;
     }
+
+    /// Adds many calls at once, which took the given duration in total.
+    pub fn record(&mut self, total: Sec, count: u64) {
+        self.total += total;
+        self.count += count;
+        self.rate += count;
+    }
 }
 
 impl AddAssign<Sec> for Stat {
@@ -0,0 +1,199 @@
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc,
+};
+
+const RUN: u8 = 0;
+const PAUSE: u8 = 1;
+const STOP: u8 = 2;
+
+/// Shared flag for stopping or pausing a [heart](crate::Hrt) from anywhere.
+/// Clones control the same heart, and can be sent to other threads.
+///
+/// A stopped heart profiles one last time before it returns.
+/// The stop request is consumed by the heart; thus, it can be started again.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::StopHandle;
+/// use std::thread;
+///
+/// let handle = StopHandle::new();
+/// let other = handle.clone();
+///
+/// thread::spawn(move || other.pause()).join().unwrap();
+/// assert!(handle.paused());
+///
+/// handle.resume();
+/// assert!(!handle.paused());
+///
+/// handle.stop();
+/// handle.resume(); // does not undo a stop
+/// assert!(handle.stopped());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StopHandle {
+    state: Arc<AtomicU8>,
+}
+
+impl StopHandle {
+    /// Creates running.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the heart to stop.
+    pub fn stop(&self) {
+        self.state.store(STOP, Ordering::Release);
+    }
+
+    /// Requests the heart to pause.
+    /// Paused heart keeps rendering and profiling, but does not update.
+    /// Time does not pile up while paused.
+    pub fn pause(&self) {
+        let _ = self
+            .state
+            .compare_exchange(RUN, PAUSE, Ordering::AcqRel, Ordering::Acquire);
+    }
+
+    /// Requests the heart to resume if it is paused.
+    pub fn resume(&self) {
+        let _ = self
+            .state
+            .compare_exchange(PAUSE, RUN, Ordering::AcqRel, Ordering::Acquire);
+    }
+
+    /// Returns whether a stop is requested.
+    pub fn stopped(&self) -> bool {
+        self.state.load(Ordering::Acquire) == STOP
+    }
+
+    /// Returns whether a pause is requested.
+    pub fn paused(&self) -> bool {
+        self.state.load(Ordering::Acquire) == PAUSE
+    }
+
+    /// Consumes the stop request.
+    pub(crate) fn reset(&self) {
+        let _ = self
+            .state
+            .compare_exchange(STOP, RUN, Ordering::AcqRel, Ordering::Acquire);
+    }
+
+    /// Gives the signals back to the actions they had before, if they stop this handle.
+    pub(crate) fn release(&self) {
+        sig::release(&self.state);
+    }
+}
+
+#[cfg(not(all(feature = "sig", unix)))]
+mod sig {
+    use std::sync::{atomic::AtomicU8, Arc};
+
+    /// Nothing to restore without the signal handlers.
+    pub(super) fn release(_: &Arc<AtomicU8>) {}
+}
+
+#[cfg(all(feature = "sig", unix))]
+mod sig {
+    use super::{StopHandle, STOP};
+    use libc::{c_int, sighandler_t, SA_RESTART, SIGINT, SIGTERM};
+    use std::{
+        io, mem, ptr,
+        sync::{
+            atomic::{AtomicPtr, AtomicU8, Ordering},
+            Arc, Mutex, PoisonError,
+        },
+    };
+
+    const SIGNALS: [c_int; 2] = [SIGINT, SIGTERM];
+
+    /// State of the handle that is stopped by the signals.
+    static TARGET: AtomicPtr<AtomicU8> = AtomicPtr::new(ptr::null_mut());
+
+    /// Actions the signals had before the handlers were installed, if they are.
+    static OLD: Mutex<Option<[libc::sigaction; 2]>> = Mutex::new(None);
+
+    extern "C" fn handle(_: c_int) {
+        let target = TARGET.load(Ordering::Acquire);
+        if !target.is_null() {
+            // Only an atomic store; safe to do in a signal handler.
+            unsafe { (*target).store(STOP, Ordering::Release) };
+        }
+    }
+
+    /// Sets the actions of the signals, and returns the previous ones.
+    /// Sets none of them if any fails.
+    fn swap(acts: &[libc::sigaction; 2]) -> io::Result<[libc::sigaction; 2]> {
+        // Zeroed actions are overwritten.
+        let mut old: [libc::sigaction; 2] = unsafe { mem::zeroed() };
+        for (i, signum) in SIGNALS.into_iter().enumerate() {
+            if unsafe { libc::sigaction(signum, &acts[i], &mut old[i]) } != 0 {
+                let err = io::Error::last_os_error();
+                for (signum, act) in SIGNALS.into_iter().zip(&old).take(i) {
+                    unsafe { libc::sigaction(signum, act, ptr::null_mut()) };
+                }
+                return Err(err);
+            }
+        }
+        Ok(old)
+    }
+
+    /// Restores the previous actions if the signals stop the given state.
+    pub(super) fn release(state: &Arc<AtomicU8>) {
+        let mut old = OLD.lock().unwrap_or_else(PoisonError::into_inner);
+        let state = Arc::as_ptr(state) as *mut AtomicU8;
+        let released = TARGET
+            .compare_exchange(state, ptr::null_mut(), Ordering::AcqRel, Ordering::Acquire)
+            .is_ok();
+        if let (true, Some(acts)) = (released, old.take()) {
+            // Nothing to report to; the heart has already stopped.
+            let _ = swap(&acts);
+        }
+    }
+
+    impl StopHandle {
+        /// Stops the heart on `SIGINT` and `SIGTERM`, like when Ctrl-C is pressed.
+        /// The heart profiles one last time and returns, instead of the process being killed.
+        ///
+        /// Replaces the handle that was registered before.
+        /// The previous actions of the signals are restored when the heart stops, for any reason,
+        /// so the signals act as they did before once the heart returns;
+        /// call this again before starting the heart again.
+        ///
+        /// # Errors
+        ///
+        /// If the signal handlers could not be installed.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use min_timer::StopHandle;
+        ///
+        /// extern "C" {
+        ///     fn raise(sig: i32) -> i32;
+        /// }
+        ///
+        /// let handle = StopHandle::new();
+        /// handle.on_signals().unwrap();
+        ///
+        /// unsafe { raise(2) }; // SIGINT
+        /// assert!(handle.stopped());
+        /// ```
+        pub fn on_signals(&self) -> io::Result<()> {
+            let mut old = OLD.lock().unwrap_or_else(PoisonError::into_inner);
+            if old.is_none() {
+                // Zeroed action has an empty mask.
+                let mut act: libc::sigaction = unsafe { mem::zeroed() };
+                act.sa_sigaction = handle as extern "C" fn(c_int) as sighandler_t;
+                act.sa_flags = SA_RESTART;
+                *old = Some(swap(&[act; 2])?);
+            }
+            // Previous state is leaked; the handler might be using it right now.
+            let state = Arc::into_raw(Arc::clone(&self.state)) as *mut AtomicU8;
+            TARGET.store(state, Ordering::Release);
+            Ok(())
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crate::{now::Now, Sec};
-use std::{
+use crate::{now::Now, Moment, Sec};
+use core::{
     fmt::Display,
     ops::{Div, Mul, Sub, SubAssign},
 };
@@ -9,38 +9,31 @@ use std::{
 /// # Example
 ///
 /// ```
-/// use std::time::{Duration, Instant};
-/// use min_timer::{Std, Sec, Timer};
+/// use min_timer::{Manual, Sec, Timer};
 ///
-/// let now = Std::new();
-/// let timer = Timer::new(&now);
+/// let now = Manual::new();
+/// let mut timer = Timer::new(&now);
 /// let duration = 5.0 * Sec::MILLI;
 ///
-/// // For stopping an infinite loop if the test fails.
-/// let start = Instant::now();
-/// let cap = Duration::from(duration + Sec::MILLI);
+/// now.advance(4.0 * Sec::MILLI);
+/// assert!(timer < duration);
 ///
-/// while timer < duration {
-///     if start.elapsed() > cap {
-///         unreachable!("Passed {}!", duration);
-///     }
-/// }
+/// now.advance(3.0 * Sec::MILLI);
+/// assert!(timer >= duration);
 ///
-/// let tolerance = 100.0 * Sec::MICRO;
-/// assert!(timer - duration < tolerance);
+/// timer -= duration;
+/// assert!(timer < duration);
+/// assert!((timer - 2.0 * Sec::MILLI).as_f64().abs() < 1e-12);
 /// ```
 #[derive(Debug)]
 pub struct Timer<'a, T: Now> {
-    start: Sec,
+    start: Moment,
     now: &'a T,
 }
 
 impl<'a, T: Now> Clone for Timer<'a, T> {
     fn clone(&self) -> Self {
-        Self {
-            start: self.start,
-            now: self.now,
-        }
+        *self
     }
 }
 
@@ -83,6 +76,8 @@ impl<'a, T: Now> Sub<Timer<'a, T>> for Sec {
 }
 
 impl<'a, T: Now> SubAssign<Sec> for Timer<'a, T> {
+    // Subtracting from the elapsed time moves the start forward.
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn sub_assign(&mut self, rhs: Sec) {
         self.start += rhs;
     }
@@ -112,6 +107,14 @@ impl<'a, T: Now> Div<f64> for Timer<'a, T> {
     }
 }
 
+impl<'a, T: Now> Div<Sec> for Timer<'a, T> {
+    type Output = f64;
+
+    fn div(self, rhs: Sec) -> Self::Output {
+        self.elapsed() / rhs
+    }
+}
+
 impl<'a, T: Now> PartialEq<Sec> for Timer<'a, T> {
     fn eq(&self, other: &Sec) -> bool {
         self.elapsed() == *other
@@ -119,13 +122,13 @@ impl<'a, T: Now> PartialEq<Sec> for Timer<'a, T> {
 }
 
 impl<'a, T: Now> PartialOrd<Sec> for Timer<'a, T> {
-    fn partial_cmp(&self, other: &Sec) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Sec) -> Option<core::cmp::Ordering> {
         self.elapsed().partial_cmp(other)
     }
 }
 
 impl<'a, T: Now> Display for Timer<'a, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.elapsed().fmt(f)
     }
 }
@@ -0,0 +1,115 @@
+use crate::Sec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// [Sec] that is totally ordered, so it can be sorted, hashed and used as a key.
+///
+/// Orders as [f64::total_cmp] does: negative not a number, negative infinity,
+/// negative amounts, negative zero, positive zero, positive amounts, positive infinity
+/// and then positive not a number.
+/// Thus, unlike [Sec], negative zero is less than positive zero,
+/// and not a number is equal to itself.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Sec, Total};
+/// use std::collections::BTreeMap;
+///
+/// let mut events = BTreeMap::new();
+/// events.insert(Total(Sec::new(2.0)), "second");
+/// events.insert(Total(Sec::new(1.0)), "first");
+/// assert_eq!(vec!["first", "second"], events.into_values().collect::<Vec<_>>());
+///
+/// let mut samples = vec![Sec::new(0.3), Sec::new(0.1), Sec::new(0.2), Sec::new(0.1)];
+/// samples.sort_by_key(|&s| Total(s));
+/// samples.dedup();
+/// assert_eq!(vec![Sec::new(0.1), Sec::new(0.2), Sec::new(0.3)], samples);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Total(pub Sec);
+
+impl Sec {
+    /// Returns the lesser, ignoring not a number like [f64::min].
+    pub fn min(self, other: Sec) -> Sec {
+        Sec::new(self.as_f64().min(other.as_f64()))
+    }
+
+    /// Returns the greater, ignoring not a number like [f64::max].
+    pub fn max(self, other: Sec) -> Sec {
+        Sec::new(self.as_f64().max(other.as_f64()))
+    }
+
+    /// Restricts to the given range like [f64::clamp].
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`, or either is not a number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use min_timer::Sec;
+    ///
+    /// let frame = Sec::new(0.5).clamp(Sec::ZERO, Sec::MILLI * 250.0);
+    /// assert_eq!(Sec::new(0.25), frame);
+    /// assert_eq!(Sec::ONE, Sec::new(-1.0).abs());
+    /// assert_eq!(Sec::ONE, Sec::ONE.max(Sec::new(f64::NAN)));
+    /// ```
+    pub fn clamp(self, min: Sec, max: Sec) -> Sec {
+        Sec::new(self.as_f64().clamp(min.as_f64(), max.as_f64()))
+    }
+
+    /// Returns the magnitude.
+    pub const fn abs(self) -> Sec {
+        Sec::new(self.as_f64().abs())
+    }
+}
+
+impl From<Sec> for Total {
+    fn from(sec: Sec) -> Self {
+        Self(sec)
+    }
+}
+
+impl From<Total> for Sec {
+    fn from(total: Total) -> Self {
+        total.0
+    }
+}
+
+impl PartialEq for Total {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Total {}
+
+impl PartialOrd for Total {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Total {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_f64().total_cmp(&other.0.as_f64())
+    }
+}
+
+impl Hash for Total {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Total order is equal only for the same bits.
+        self.0.as_f64().to_bits().hash(state);
+    }
+}
+
+impl Display for Total {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{float, Sec};
+use core::fmt::{self, Alignment, Display, Formatter, Write};
+
+/// Unit of time, which a [Sec] can be expressed in.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Sec, Unit};
+///
+/// let dur = Sec::new(0.25);
+///
+/// assert_eq!(250.0, dur.as_unit(Unit::Milli));
+/// assert_eq!(dur, Sec::from_unit(250.0, Unit::Milli));
+/// assert_eq!(Unit::Milli, Unit::fit(dur));
+/// assert_eq!("250000 µs", format!("{}", dur.display(Unit::Micro)));
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Unit {
+    /// Nano second, ns.
+    Nano,
+    /// Micro second, µs.
+    Micro,
+    /// Milli second, ms.
+    Milli,
+    /// Second, s.
+    One,
+    /// Minute, min.
+    Minute,
+    /// Kilo second, ks.
+    Kilo,
+    /// Hour, h.
+    Hour,
+    /// Day, d.
+    Day,
+    /// Mega second, Ms.
+    Mega,
+    /// Giga second, Gs.
+    Giga,
+}
+
+impl Unit {
+    /// Units that are picked automatically, from the largest to the smallest.
+    pub const SCALE: [Unit; 7] = [
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::One,
+        Unit::Milli,
+        Unit::Micro,
+        Unit::Nano,
+    ];
+
+    /// Returns the duration of one of this unit.
+    pub const fn sec(self) -> Sec {
+        match self {
+            Self::Nano => Sec::NANO,
+            Self::Micro => Sec::MICRO,
+            Self::Milli => Sec::MILLI,
+            Self::One => Sec::ONE,
+            Self::Minute => Sec::MINUTE,
+            Self::Kilo => Sec::KILO,
+            Self::Hour => Sec::HOUR,
+            Self::Day => Sec::DAY,
+            Self::Mega => Sec::MEGA,
+            Self::Giga => Sec::GIGA,
+        }
+    }
+
+    /// Returns the symbol.
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Nano => "ns",
+            Self::Micro => "µs",
+            Self::Milli => "ms",
+            Self::One => "s",
+            Self::Minute => "min",
+            Self::Kilo => "ks",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Mega => "Ms",
+            Self::Giga => "Gs",
+        }
+    }
+
+    /// Finds the largest unit in the [scale](Unit::SCALE) that is not longer than the duration.
+    /// Zero, non-finite and shorter than a nano second durations are in seconds and nano seconds.
+    pub fn fit(sec: Sec) -> Unit {
+        let amt = sec.as_f64().abs();
+        if amt == 0.0 || !amt.is_finite() {
+            return Self::One;
+        }
+        Self::SCALE
+            .into_iter()
+            .find(|unit| amt >= unit.sec().as_f64())
+            .unwrap_or(Self::Nano)
+    }
+
+    /// Finds the unit with the given symbol.
+    /// Besides the symbols, `us` and `μs` are micro seconds, and `m` is minutes.
+    pub fn from_symbol(symbol: &str) -> Option<Unit> {
+        Some(match symbol {
+            "ns" => Self::Nano,
+            "µs" | "μs" | "us" => Self::Micro,
+            "ms" => Self::Milli,
+            "s" => Self::One,
+            "min" | "m" => Self::Minute,
+            "ks" => Self::Kilo,
+            "h" => Self::Hour,
+            "d" => Self::Day,
+            "Ms" => Self::Mega,
+            "Gs" => Self::Giga,
+            _ => return None,
+        })
+    }
+
+    /// Returns the power of ten of the unit, if it is decimal.
+    pub(crate) const fn pow(self) -> Option<i32> {
+        match self {
+            Self::Nano => Some(-9),
+            Self::Micro => Some(-6),
+            Self::Milli => Some(-3),
+            Self::One => Some(0),
+            Self::Kilo => Some(3),
+            Self::Mega => Some(6),
+            Self::Giga => Some(9),
+            Self::Minute | Self::Hour | Self::Day => None,
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+impl Sec {
+    /// Returns the amount in the given unit.
+    pub fn as_unit(&self, unit: Unit) -> f64 {
+        // Powers of ten are exact, unlike the submultiples themselves.
+        match unit.pow() {
+            Some(pow) if pow < 0 => self.as_f64() * float::powi(10.0, -pow),
+            Some(pow) => self.as_f64() / float::powi(10.0, pow),
+            None => self.as_f64() / unit.sec().as_f64(),
+        }
+    }
+
+    /// Creates from the given amount of the given unit.
+    pub fn from_unit(amt: f64, unit: Unit) -> Self {
+        match unit.pow() {
+            Some(pow) if pow < 0 => Self::new(amt / float::powi(10.0, -pow)),
+            Some(pow) => Self::new(amt * float::powi(10.0, pow)),
+            None => Self::new(amt * unit.sec().as_f64()),
+        }
+    }
+
+    /// Formats in the given unit, instead of the one picked automatically.
+    pub fn display(&self, unit: Unit) -> Scaled {
+        Scaled { sec: *self, unit }
+    }
+}
+
+/// [Sec] that is formatted in a fixed unit.
+/// Precision of the formatter is applied to the amount, and width to the whole.
+///
+/// Without a precision, decimal units are written exactly;
+/// the digits of the seconds are shifted instead of the amount being scaled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scaled {
+    sec: Sec,
+    unit: Unit,
+}
+
+impl Scaled {
+    /// Writes the amount without the symbol.
+    pub(crate) fn amt(&self, f: &mut Formatter<'_>, prec: Option<usize>) -> fmt::Result {
+        match (prec, self.unit.pow()) {
+            (Some(prec), _) => write!(f, "{:.*}", prec, self.sec.as_unit(self.unit)),
+            (None, Some(pow)) => shift(f, self.sec.as_f64(), -pow),
+            (None, None) => write!(f, "{}", self.sec.as_unit(self.unit)),
+        }
+    }
+}
+
+impl Display for Scaled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let prec = f.precision();
+        let amt = Amt(*self, prec);
+        pad(f, format_args!("{} {}", amt, self.unit.symbol()))
+    }
+}
+
+/// Amount of a [Scaled] with the given precision.
+pub(crate) struct Amt(pub(crate) Scaled, pub(crate) Option<usize>);
+
+impl Display for Amt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.amt(f, self.1)
+    }
+}
+
+/// Writes the amount times the given power of ten, with the shortest digits that turn back to it.
+pub(crate) fn shift(f: &mut Formatter<'_>, amt: f64, pow: i32) -> fmt::Result {
+    if pow == 0 || amt == 0.0 || !amt.is_finite() {
+        return write!(f, "{}", amt);
+    }
+    let mut buf = Buf::<32>::new();
+    write!(buf, "{:e}", amt.abs())?;
+    let (mant, exp) = buf.as_str().split_once('e').ok_or(fmt::Error)?;
+    let exp: i32 = exp.parse().map_err(|_| fmt::Error)?;
+    let (head, tail) = mant.split_once('.').unwrap_or((mant, ""));
+    let digits = head.len() as i32 + tail.len() as i32;
+    // Amount of digits before the point.
+    let int = 1 + exp + pow;
+
+    if amt < 0.0 {
+        f.write_char('-')?;
+    }
+    let digit = |i: i32| {
+        let i = i as usize;
+        if i < head.len() {
+            &head[i..i + 1]
+        } else {
+            &tail[i - head.len()..i - head.len() + 1]
+        }
+    };
+    if int <= 0 {
+        f.write_str("0.")?;
+        for _ in int..0 {
+            f.write_char('0')?;
+        }
+        for i in 0..digits {
+            f.write_str(digit(i))?;
+        }
+    } else {
+        for i in 0..int {
+            f.write_str(if i < digits { digit(i) } else { "0" })?;
+        }
+        if int < digits {
+            f.write_char('.')?;
+            for i in int..digits {
+                f.write_str(digit(i))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Small buffer on the stack.
+pub(crate) struct Buf<const N: usize = 32> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buf<N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            data: [0; N],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // Only strings are written.
+        core::str::from_utf8(&self.data[..self.len]).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> Write for Buf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.data.len() {
+            return Err(fmt::Error);
+        }
+        self.data[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Counts the characters that are written.
+struct Count(usize);
+
+impl Write for Count {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes the arguments, filling up to the width of the formatter.
+/// Aligns to the right unless told otherwise, like numbers.
+pub(crate) fn pad(f: &mut Formatter<'_>, args: fmt::Arguments<'_>) -> fmt::Result {
+    let width = match f.width() {
+        Some(width) => width,
+        None => return f.write_fmt(args),
+    };
+    let mut count = Count(0);
+    count.write_fmt(args)?;
+    let fill = width.saturating_sub(count.0);
+    let (pre, post) = match f.align() {
+        Some(Alignment::Left) => (0, fill),
+        Some(Alignment::Center) => (fill / 2, fill - fill / 2),
+        Some(Alignment::Right) | None => (fill, 0),
+    };
+    let c = f.fill();
+    for _ in 0..pre {
+        f.write_char(c)?;
+    }
+    f.write_fmt(args)?;
+    for _ in 0..post {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use crate::{Moment, Now};
+use core::cell::Cell;
+
+/// [Now] that runs another one slower, faster or not at all.
+/// Time can be scaled or paused through a shared reference,
+/// thus it can be changed while timers and hearts are borrowing it.
+///
+/// Changes only affect the time after them; the time never jumps.
+/// It starts at the time of the other one, and wraps any [Now],
+/// so the clocks can be nested, like real time, game time and cutscene time.
+///
+/// # Example
+///
+/// ```
+/// use min_timer::{Manual, Now, Sec, Timer, Warp};
+///
+/// let real = Manual::new();
+/// let game = Warp::new(&real);
+/// let cutscene = Warp::new(&game);
+/// let timer = Timer::new(&cutscene);
+///
+/// game.set_scale(0.25);
+/// real.advance(Sec::new(4.0));
+/// assert_eq!(Sec::ONE, timer.elapsed());
+///
+/// game.pause();
+/// real.advance(Sec::new(4.0));
+/// assert_eq!(Sec::ONE, timer.elapsed());
+///
+/// game.resume();
+/// cutscene.set_scale(2.0);
+/// real.advance(Sec::new(4.0));
+/// assert_eq!(Sec::new(3.0), timer.elapsed());
+/// ```
+#[derive(Debug)]
+pub struct Warp<'a, T: Now> {
+    now: &'a T,
+    /// Time of the other one at the last change.
+    base: Cell<Moment>,
+    /// Own time at the last change.
+    anchor: Cell<Moment>,
+    scale: Cell<f64>,
+    paused: Cell<bool>,
+}
+
+impl<'a, T: Now> Warp<'a, T> {
+    /// Creates at the time of the given one, running at the same rate.
+    pub fn new(now: &'a T) -> Self {
+        let time = now.now();
+        Self {
+            now,
+            base: Cell::new(time),
+            anchor: Cell::new(time),
+            scale: Cell::new(1.0),
+            paused: Cell::new(false),
+        }
+    }
+
+    /// Returns the clock that is wrapped.
+    pub fn inner(&self) -> &'a T {
+        self.now
+    }
+
+    /// Returns how many seconds pass for every second of the wrapped clock.
+    pub fn scale(&self) -> f64 {
+        self.scale.get()
+    }
+
+    /// Whether the time is stopped.
+    pub fn paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// Sets how many seconds pass for every second of the wrapped clock.
+    ///
+    /// # Panics
+    ///
+    /// If the scale is negative, infinite or not a number; use [pause](Warp::pause) for stopping the time.
+    pub fn set_scale(&self, scale: f64) {
+        assert!(
+            scale.is_finite() && scale >= 0.0,
+            "scale {} is not a finite, non-negative number",
+            scale
+        );
+        self.rebase();
+        self.scale.set(scale);
+    }
+
+    /// Stops the time, until it is resumed.
+    ///
+    /// A running heart on a paused clock does not update, but keeps waking up
+    /// as often as it would on the real time, thus it can still be stopped through its handle.
+    pub fn pause(&self) {
+        self.rebase();
+        self.paused.set(true);
+    }
+
+    /// Continues the time from where it was paused.
+    pub fn resume(&self) {
+        self.rebase();
+        self.paused.set(false);
+    }
+
+    /// Starts measuring from this moment, keeping the time the same.
+    fn rebase(&self) {
+        let base = self.now.now();
+        self.anchor.set(self.at(base));
+        self.base.set(base);
+    }
+
+    /// Finds the own time at the given time of the wrapped clock.
+    fn at(&self, base: Moment) -> Moment {
+        if self.paused.get() {
+            return self.anchor.get();
+        }
+        self.anchor.get() + (base - self.base.get()) * self.scale.get()
+    }
+}
+
+impl<'a, T: Now> Now for Warp<'a, T> {
+    fn now(&self) -> Moment {
+        self.at(self.now.now())
+    }
+}
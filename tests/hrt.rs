@@ -0,0 +1,342 @@
+#![cfg(feature = "hrt")]
+
+use min_timer::{
+    Catch, Hrt, Hz, Idle, Interpolate, Lim, Manual, Now, Pair, Render, Sec, Sim, Std, Stt, Timer,
+    Warp,
+};
+use std::{thread, time::Duration};
+
+/// Counts the ticks and profiles, and stops or fails at the given ticks.
+#[derive(Debug, Clone, Copy, Default)]
+struct Cnt {
+    ticks: u32,
+    secs: u32,
+    /// Tick and frame rates at the last profile.
+    rates: (u64, u64),
+    stop: Option<u32>,
+    fail: Option<u32>,
+}
+
+impl Cnt {
+    fn stop(ticks: u32) -> Self {
+        Self {
+            stop: Some(ticks),
+            ..Self::default()
+        }
+    }
+
+    fn fail(ticks: u32) -> Self {
+        Self {
+            fail: Some(ticks),
+            ..Self::default()
+        }
+    }
+}
+
+impl Interpolate for Cnt {
+    fn lerp(&self, other: &Self, _: f64) -> Self {
+        *other
+    }
+}
+
+impl<T: Now> Stt<T> for Cnt {
+    type Err = String;
+
+    fn init(&mut self, _: &mut Hrt<T>, _: Timer<T>) -> Result<(), Self::Err> {
+        Ok(())
+    }
+
+    fn update(&mut self, hrt: &mut Hrt<T>) -> Result<(), Self::Err> {
+        self.ticks += 1;
+        if self.fail == Some(self.ticks) {
+            return Err(format!("failed at {}", self.ticks));
+        }
+        if self.stop == Some(self.ticks) {
+            hrt.stop();
+        }
+        Ok(())
+    }
+
+    fn sec(&mut self, hrt: &mut Hrt<T>) -> Result<(), Self::Err> {
+        self.secs += 1;
+        self.rates = (hrt.ticks().rate(), hrt.frames().rate());
+        Ok(())
+    }
+}
+
+/// Renders nothing.
+#[derive(Debug)]
+struct Nop;
+
+impl<T: Now> Render<T, Cnt> for Nop {
+    fn render(&mut self, _: &Hrt<T>, _: &Cnt) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Every frame takes the given time, except the stall at the given frame.
+#[derive(Debug)]
+struct Clk {
+    frame: Sec,
+    stall: Option<(u32, Sec)>,
+    frames: u32,
+}
+
+impl Clk {
+    fn new(frame: Sec) -> Self {
+        Self {
+            frame,
+            stall: None,
+            frames: 0,
+        }
+    }
+}
+
+impl Render<Manual, Cnt> for Clk {
+    fn render(&mut self, hrt: &Hrt<Manual>, _: &Cnt) -> Result<(), String> {
+        self.frames += 1;
+        match self.stall {
+            Some((at, stall)) if at == self.frames => hrt.now().advance(stall),
+            _ => hrt.now().advance(self.frame),
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn drop_counts_the_dropped_ticks() {
+    let now = Manual::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    hrt.set_catch(Catch::Drop(4));
+    let clk = Clk {
+        stall: Some((8, Sec::new(128.0 / 256.0))),
+        ..Clk::new(Sec::new(1.0 / 256.0))
+    };
+    hrt.run(Cnt::stop(6), clk).unwrap();
+
+    // One tick before the stall, four after it, and one more.
+    assert_eq!(6, hrt.ticks().count());
+    // Stall took 32.75 ticks; the quarter tick is kept.
+    assert_eq!(28, hrt.drops().count());
+    assert_eq!(Sec::new(28.0 / 64.0), hrt.drops().total());
+}
+
+#[test]
+fn zero_cap_still_updates() {
+    for catch in [Catch::Drop(0), Catch::Slow(0)] {
+        let now = Manual::new();
+        let mut hrt = Hrt::new(Hz::new(64.0), &now);
+        hrt.set_catch(catch);
+        let mut stt = hrt.begin(Cnt::default()).unwrap();
+        for _ in 0..64 {
+            now.advance(Sec::new(1.0 / 64.0));
+            hrt.step(&mut stt, &mut Nop).unwrap();
+        }
+        assert_eq!(64, stt.cur.ticks);
+    }
+}
+
+#[test]
+fn sleeping_wakes_up_only_when_due() {
+    let now = Manual::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    hrt.set_lim(Lim::Fps(Hz::new(32.0)));
+    hrt.set_idle(Idle::Sleep);
+    let mut stt = hrt.begin(Cnt::stop(128)).unwrap();
+    let mut steps = 0;
+
+    // Sleeping exactly until the next tick or frame, which is always ahead.
+    while hrt.step(&mut stt, &mut Nop).unwrap() {
+        let next = hrt.next();
+        assert!(next > Sec::ZERO);
+        now.advance(next);
+        steps += 1;
+    }
+
+    // Frames fall on the ticks, thus the heart only wakes up for the ticks.
+    assert_eq!(128, steps);
+    assert_eq!(2, stt.cur.secs);
+    assert_eq!((64, 32), stt.cur.rates);
+}
+
+#[test]
+fn failure_keeps_the_statistics() {
+    let now = Manual::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    let fail = hrt
+        .run(Cnt::fail(3), Clk::new(Sec::new(1.0 / 64.0)))
+        .unwrap_err();
+
+    assert_eq!("failed at 3", fail.err);
+    assert_eq!(3, fail.ticks.count());
+    assert_eq!(3, fail.frames.count());
+    assert!(!hrt.beating());
+}
+
+#[test]
+fn pausing_skips_the_updates() {
+    let now = Manual::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    let handle = hrt.handle();
+    let mut stt = hrt.begin(Cnt::default()).unwrap();
+    let mut run = |n| {
+        for _ in 0..n {
+            now.advance(Sec::new(1.0 / 64.0));
+            hrt.step(&mut stt, &mut Nop).unwrap();
+        }
+    };
+
+    run(16);
+    handle.pause();
+    run(16);
+    handle.resume();
+    run(16);
+    assert_eq!(32, stt.cur.ticks);
+}
+
+#[test]
+fn stopping_from_another_thread() {
+    let now = Std::new();
+    let mut hrt = Hrt::new(Hz::new(100.0), &now);
+    hrt.set_lim(Lim::Fps(Hz::new(30.0)));
+    hrt.set_idle(Idle::Sleep);
+    let handle = hrt.handle();
+
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        handle.stop();
+    });
+
+    let (cnt, _) = hrt.run(Cnt::default(), Nop).unwrap();
+    assert!(cnt.ticks > 0);
+    assert_eq!(1, cnt.secs); // the last profile
+}
+
+#[test]
+fn stopping_while_the_clock_is_paused() {
+    let now = Std::new();
+    let game = Warp::new(&now);
+    game.pause();
+    let mut hrt = Hrt::new(Hz::new(100.0), &game);
+    hrt.set_lim(Lim::Never);
+    let handle = hrt.handle();
+
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        handle.stop();
+    });
+
+    let (cnt, _) = hrt.run(Cnt::default(), Nop).unwrap();
+    assert_eq!(0, cnt.ticks);
+}
+
+#[test]
+fn sleeping_follows_a_fast_clock() {
+    let now = Std::new();
+    let game = Warp::new(&now);
+    game.set_scale(4.0);
+    let mut hrt = Hrt::new(Hz::new(10.0), &game);
+    hrt.set_lim(Lim::Fps(Hz::new(20.0)));
+    hrt.set_idle(Idle::Sleep);
+    hrt.run(Cnt::stop(20), Nop).unwrap();
+
+    // Two seconds of the clock; oversleeping by the scale would give about ten frames.
+    assert!(hrt.frames().count() >= 30);
+}
+
+#[test]
+fn stepping_an_event_loop() {
+    let now = Manual::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    let mut stt = hrt.begin(Cnt::default()).unwrap();
+
+    for _ in 0..256 {
+        // Rendering is unlimited, thus there is no time to wait.
+        assert_eq!(Sec::ZERO, hrt.next());
+        now.advance(Sec::new(1.0 / 256.0));
+        hrt.step(&mut stt, &mut Nop).unwrap();
+    }
+
+    assert_eq!(64, stt.cur.ticks);
+    assert_eq!(64, hrt.ticks().count());
+    assert_eq!(256, hrt.frames().count());
+}
+
+#[test]
+fn stepping_does_nothing_unless_running() {
+    let now = Manual::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    let mut stt = Pair::new(Cnt::default());
+
+    // Before beginning
+    now.advance(Sec::new(10.0 / 64.0));
+    assert!(!hrt.step(&mut stt, &mut Nop).unwrap());
+    assert_eq!(0, stt.cur.ticks);
+
+    // After stopping
+    let mut stt = hrt.begin(Cnt::stop(2)).unwrap();
+    now.advance(Sec::new(4.0 / 64.0));
+    assert!(!hrt.step(&mut stt, &mut Nop).unwrap());
+    assert_eq!(4, stt.cur.ticks); // the due ones still run
+    now.advance(Sec::new(4.0 / 64.0));
+    assert!(!hrt.step(&mut stt, &mut Nop).unwrap());
+    assert_eq!(4, stt.cur.ticks);
+    assert_eq!(1, hrt.frames().count());
+}
+
+#[test]
+fn headless_profiles_every_simulated_second() {
+    let now = Std::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    let sim = Sim {
+        ticks: Some(64 * 60 * 60), // an hour
+        every: Some(4),
+    };
+    let (cnt, _) = hrt.sim(Cnt::default(), Nop, sim).unwrap();
+
+    assert_eq!(64 * 60 * 60, cnt.ticks);
+    assert_eq!(60 * 60, cnt.secs);
+    assert_eq!((64, 16), cnt.rates);
+    assert_eq!(16 * 60 * 60, hrt.frames().count());
+}
+
+#[test]
+fn headless_seconds_do_not_drift() {
+    for rate in [30, 60, 144] {
+        let now = Manual::new();
+        let mut hrt = Hrt::new(Hz::new(rate as f64), &now);
+        let sim = Sim {
+            ticks: Some(rate * 60 * 60),
+            every: None,
+        };
+        let (cnt, _) = hrt.sim(Cnt::default(), Nop, sim).unwrap();
+
+        assert_eq!(60 * 60, cnt.secs);
+        assert_eq!((rate, 0), cnt.rates);
+    }
+}
+
+#[cfg(all(feature = "sig", unix))]
+#[test]
+fn signals_are_given_back_when_stopped() {
+    fn action() -> libc::sighandler_t {
+        unsafe {
+            let mut act: libc::sigaction = std::mem::zeroed();
+            libc::sigaction(libc::SIGINT, std::ptr::null(), &mut act);
+            act.sa_sigaction
+        }
+    }
+
+    unsafe { libc::signal(libc::SIGINT, libc::SIG_IGN) };
+    let now = Manual::new();
+    let mut hrt = Hrt::new(Hz::new(64.0), &now);
+    hrt.handle().on_signals().unwrap();
+    assert_ne!(libc::SIG_IGN, action());
+
+    // Stopping from the update, not through the handle
+    hrt.run(Cnt::stop(3), Clk::new(Sec::new(1.0 / 64.0)))
+        .unwrap();
+    assert_eq!(libc::SIG_IGN, action());
+
+    unsafe { libc::signal(libc::SIGINT, libc::SIG_DFL) };
+}